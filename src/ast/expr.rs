@@ -11,6 +11,7 @@ use crate::{
             class_member_expression::ClassMemberExpression,
             decorator::Decorator,
             double_literal::DoubleLiteral,
+            format_string_literal::FormatStringLiteral,
             function_expression::FunctionExpression,
             hash_literal::HashLiteral,
             identifier::Identifier,
@@ -19,11 +20,13 @@ use crate::{
             infix_expression::InfixExpression,
             integer_literal::IntegerLiteral,
             integer64_literal::Int64Literal,
+            match_expression::MatchExpression,
             none_literal::NoneLiteral,
             object_member_expression::ObjectMemberExpression,
             prefix_expression::PrefixExpression,
             string_literal::StringLiteral,
             tuple_expression::TupleExpression,
+            uninit_literal::UninitLiteral,
         },
     },
     token::token::Token,
@@ -39,6 +42,7 @@ pub enum Expression {
     ClassMemberExpression,
     Decorator,
     DoubleLiteral,
+    FormatStringLiteral,
     FunctionExpression,
     HashLiteral,
     Identifier,
@@ -47,11 +51,13 @@ pub enum Expression {
     InfixExpression,
     IntegerLiteral,
     Int64Literal,
+    MatchExpression,
     NoneLiteral,
     ObjectMemberExpression,
     PrefixExpression,
     StringLiteral,
     TupleExpression,
+    UninitLiteral,
     ElseIfExpression,
 }
 
@@ -65,6 +71,7 @@ macro_rules! auto_expr {
             Expression::ClassMemberExpression(expr) => expr.$method(),
             Expression::Decorator(expr) => expr.$method(),
             Expression::DoubleLiteral(expr) => expr.$method(),
+            Expression::FormatStringLiteral(expr) => expr.$method(),
             Expression::FunctionExpression(expr) => expr.$method(),
             Expression::HashLiteral(expr) => expr.$method(),
             Expression::Identifier(expr) => expr.$method(),
@@ -79,6 +86,8 @@ macro_rules! auto_expr {
             Expression::TupleExpression(expr) => expr.$method(),
             Expression::ElseIfExpression(expr) => expr.$method(),
             Expression::Int64Literal(expr) => expr.$method(),
+            Expression::MatchExpression(expr) => expr.$method(),
+            Expression::UninitLiteral(expr) => expr.$method(),
         }
     };
 }
@@ -20,7 +20,12 @@ impl INode for AssignmentExpression {
     }
 
     fn to_string(&self) -> String {
-        format!("{} = {}", self.left.to_string(), self.value.to_string())
+        format!(
+            "{} {} {}",
+            self.left.to_string(),
+            self.token.value,
+            self.value.to_string()
+        )
     }
 }
 
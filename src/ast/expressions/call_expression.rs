@@ -7,6 +7,7 @@ use crate::token::token::Token;
 pub struct CallExpression {
     pub func: Box<Expression>,
     pub args: Vec<Box<Expression>>,
+    pub keyword_args: Vec<(String, Box<Expression>)>, // f(a, b: 1)
     pub token: Token,
 }
 
@@ -26,6 +27,10 @@ impl INode for CallExpression {
             args_strings.push(arg.to_string())
         }
 
+        for (name, arg) in &self.keyword_args {
+            args_strings.push(format!("{name}: {}", arg.to_string()))
+        }
+
         format!("{}({})", self.func.to_string(), args_strings.join(", "))
     }
 }
@@ -36,6 +41,7 @@ pub fn create_call_expression(
     token: Token,
     func: Box<Expression>,
     args: Vec<Box<Expression>>,
+    keyword_args: Vec<(String, Box<Expression>)>,
 ) -> CallExpression {
-    CallExpression { token, func, args }
+    CallExpression { token, func, args, keyword_args }
 }
@@ -0,0 +1,48 @@
+use crate::ast::ast::{IExpression, INode};
+
+use crate::ast::expr::Expression;
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub enum FormatStringPart {
+    Literal(String),
+    Expression(Box<Expression>, Option<String>), // 表达式与格式说明符
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatStringLiteral {
+    pub parts: Vec<FormatStringPart>,
+    pub token: Token,
+}
+
+impl INode for FormatStringLiteral {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let mut s = String::new();
+
+        for part in &self.parts {
+            match part {
+                FormatStringPart::Literal(it) => s.push_str(&it.replace('{', "{{").replace('}', "}}")),
+                FormatStringPart::Expression(expr, None) => s.push_str(&format!("{{{}}}", expr.to_string())),
+                FormatStringPart::Expression(expr, Some(spec)) => {
+                    s.push_str(&format!("{{{}:{spec}}}", expr.to_string()))
+                }
+            }
+        }
+
+        format!("f\"{s}\"")
+    }
+}
+
+impl IExpression for FormatStringLiteral {}
+
+pub fn create_format_string_literal(token: Token, parts: Vec<FormatStringPart>) -> FormatStringLiteral {
+    FormatStringLiteral { token, parts }
+}
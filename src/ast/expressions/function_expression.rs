@@ -1,17 +1,53 @@
+use std::fmt::Display;
+
 use crate::ast::ast::{IExpression, INode};
 use crate::ast::expr::Expression;
 use crate::ast::expressions::identifier::Identifier;
 use crate::ast::statements::block_statement::BlockStatement;
-use crate::ast::utils::expressions_to_string;
 
 use crate::token::token::Token;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    Normal,   // a 或 a = 1
+    Rest,     // *rest
+    Keywords, // **opts
+}
+
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub name: Identifier,
+    pub kind: ParamKind,
+    pub type_hint: Option<Vec<String>>, // a: Int | String
+    pub default: Option<Box<Expression>>,
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParamKind::Normal => write!(f, "{}", self.name.value)?,
+            ParamKind::Rest => write!(f, "*{}", self.name.value)?,
+            ParamKind::Keywords => write!(f, "**{}", self.name.value)?,
+        }
+
+        if let Some(type_hint) = &self.type_hint {
+            write!(f, ": {}", type_hint.join(" | "))?;
+        }
+
+        if let Some(default) = &self.default {
+            write!(f, " = {}", default.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionExpression {
     pub token: Token,
     pub name: Option<String>,
-    pub params: Vec<Box<Expression>>,
-    pub return_type: Option<Identifier>,
+    pub params: Vec<Parameter>,
+    pub return_type: Option<Vec<String>>,
     pub block: BlockStatement,
 }
 
@@ -28,7 +64,7 @@ impl INode for FunctionExpression {
         format!(
             "func {}({}) {{\n{}}}",
             if let Some(it) = &self.name { it } else { "" },
-            expressions_to_string(&self.params, ", "),
+            self.params.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", "),
             self.block.to_string()
         )
     }
@@ -39,9 +75,9 @@ impl IExpression for FunctionExpression {}
 pub fn create_function_expression(
     token: Token,
     name: Option<String>,
-    params: Vec<Box<Expression>>,
+    params: Vec<Parameter>,
     block: BlockStatement,
-    return_type: Option<Identifier>
+    return_type: Option<Vec<String>>
 ) -> FunctionExpression {
     FunctionExpression {
         token,
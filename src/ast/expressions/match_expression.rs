@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::ast::ast::{IExpression, INode};
+
+use crate::ast::expr::Expression;
+use crate::ast::stmt::Statement;
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Wildcard,                                // _
+    Literal(Box<Expression>),                // 1, -1, "s", true, none
+    Binding(String),                         // x
+    Array(Vec<Pattern>),                     // [p1, p2]
+    Hash(Vec<(Box<Expression>, Pattern)>),   // {"k": p}
+    Class(String, Vec<(String, Pattern)>),   // A { field: p }
+    Variant(String, String, Vec<(String, Pattern)>), // E.V { field: p }, 按所属枚举与变体匹配
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pattern::Wildcard => write!(f, "_"),
+            Pattern::Literal(expr) => write!(f, "{}", expr.to_string()),
+            Pattern::Binding(name) => write!(f, "{name}"),
+            Pattern::Array(items) => write!(
+                f,
+                "[{}]",
+                items.iter().map(|it| it.to_string()).collect::<Vec<String>>().join(", ")
+            ),
+            Pattern::Hash(pairs) => write!(
+                f,
+                "{{{}}}",
+                pairs
+                    .iter()
+                    .map(|(k, v)| format!("{}: {v}", k.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Pattern::Class(name, fields) => write!(
+                f,
+                "{name} {{{}}}",
+                fields
+                    .iter()
+                    .map(|(k, v)| format!("{k}: {v}"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Pattern::Variant(enum_name, name, fields) => write!(
+                f,
+                "{enum_name}.{name} {{{}}}",
+                fields
+                    .iter()
+                    .map(|(k, v)| format!("{k}: {v}"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Box<Expression>>, // if 守卫
+    pub body: Statement,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchExpression {
+    pub token: Token,
+    pub value: Box<Expression>,
+    pub arms: Vec<MatchArm>,
+}
+
+impl INode for MatchExpression {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let arms_string = self
+            .arms
+            .iter()
+            .map(|arm| match &arm.guard {
+                Some(guard) => format!(
+                    "{} if {} => {{{}}}",
+                    arm.pattern,
+                    guard.to_string(),
+                    arm.body.to_string()
+                ),
+                None => format!("{} => {{{}}}", arm.pattern, arm.body.to_string()),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!("match {} {{{}}}", self.value.to_string(), arms_string)
+    }
+}
+
+impl IExpression for MatchExpression {}
+
+pub fn create_match_expression(token: Token, value: Box<Expression>, arms: Vec<MatchArm>) -> MatchExpression {
+    MatchExpression { token, value, arms }
+}
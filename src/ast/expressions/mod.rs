@@ -4,12 +4,14 @@ pub mod boolean_literal;
 pub mod call_expression;
 pub mod class_member_expression;
 pub mod double_literal;
+pub mod format_string_literal;
 pub mod function_expression;
 pub mod identifier;
 pub mod if_expression;
 pub mod index_expression;
 pub mod infix_expression;
 pub mod integer_literal;
+pub mod match_expression;
 pub mod object_member_expression;
 pub mod prefix_expression;
 pub mod string_literal;
@@ -18,3 +20,5 @@ pub mod none_literal;
 pub mod hash_literal;
 pub mod decorator;
 pub mod integer64_literal;
+
+pub mod uninit_literal;
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::ast::ast::{IExpression, INode};
+use crate::token::token::Token;
+
+// 没有值的成员声明 (let area;), 值为 Uninit, 需要由实现接口的类定义
+#[derive(Debug, Clone)]
+pub struct UninitLiteral {
+    pub token: Token,
+}
+
+impl INode for UninitLiteral {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        "uninit".to_string()
+    }
+}
+
+impl IExpression for UninitLiteral {}
+
+pub fn create_uninit_literal(token: Token) -> UninitLiteral {
+    UninitLiteral { token }
+}
@@ -11,6 +11,7 @@ pub struct ClassStatement {
     pub name: Identifier,
     pub base: Option<Identifier>,
     pub block: BlockStatement,
+    pub interface: bool, // interface 声明, 可以含有未定义 (Uninit) 的成员
 }
 
 impl INode for ClassStatement {
@@ -23,16 +24,18 @@ impl INode for ClassStatement {
     }
 
     fn to_string(&self) -> String {
+        let keyword = if self.interface { "interface" } else { "class" };
+
         if let Some(base) = &self.base {
             format!(
-                "class {}: {} {{{}}}",
+                "{keyword} {}: {} {{{}}}",
                 self.name.to_string(),
                 base.to_string(),
                 self.block.to_string()
             )
         } else {
             format!(
-                "class {} {{{}}}",
+                "{keyword} {} {{{}}}",
                 self.name.to_string(),
                 self.block.to_string()
             )
@@ -47,11 +50,13 @@ pub fn create_class_statement(
     name: Identifier,
     base: Option<Identifier>,
     block: BlockStatement,
+    interface: bool,
 ) -> ClassStatement {
     ClassStatement {
         token,
         base,
         name,
         block,
+        interface,
     }
 }
@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::ast::ast::{INode, IStatement};
+
+use crate::ast::expr::Expression;
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub enum DestructureTarget {
+    Name(String),                                        // a (_ 表示忽略)
+    Array(Vec<DestructureTarget>, Option<String>),       // [a, b, ...rest]
+    Hash(Vec<(String, DestructureTarget)>),              // {name, age: a}
+    Object(String, Vec<(String, DestructureTarget)>),    // Point {x, y}
+}
+
+fn fields_to_string(fields: &[(String, DestructureTarget)]) -> String {
+    fields
+        .iter()
+        .map(|(k, v)| format!("{k}: {v}"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl Display for DestructureTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DestructureTarget::Name(name) => write!(f, "{name}"),
+            DestructureTarget::Array(items, rest) => {
+                let mut items = items.iter().map(|it| it.to_string()).collect::<Vec<String>>();
+
+                if let Some(rest) = rest {
+                    items.push(format!("...{rest}"));
+                }
+
+                write!(f, "[{}]", items.join(", "))
+            }
+            DestructureTarget::Hash(fields) => write!(f, "{{{}}}", fields_to_string(fields)),
+            DestructureTarget::Object(name, fields) => {
+                write!(f, "{name} {{{}}}", fields_to_string(fields))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DestructuringLetStatement {
+    pub token: Token,
+    pub target: DestructureTarget,
+    pub value: Box<Expression>,
+}
+
+impl INode for DestructuringLetStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        format!("let {} = {}", self.target, self.value.to_string())
+    }
+}
+
+impl IStatement for DestructuringLetStatement {}
+
+pub fn create_destructuring_let_statement(
+    token: Token,
+    target: DestructureTarget,
+    value: Box<Expression>,
+) -> DestructuringLetStatement {
+    DestructuringLetStatement { token, target, value }
+}
@@ -0,0 +1,52 @@
+use crate::ast::ast::{INode, IStatement};
+use crate::ast::expressions::identifier::Identifier;
+
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub struct EnumVariantDecl {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumStatement {
+    pub token: Token,
+    pub name: Identifier,
+    pub variants: Vec<EnumVariantDecl>,
+}
+
+impl INode for EnumStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let variants = self
+            .variants
+            .iter()
+            .map(|it| if it.fields.is_empty() {
+                it.name.clone()
+            } else {
+                format!("{}({})", it.name, it.fields.join(", "))
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!("enum {} {{{variants}}}", self.name.to_string())
+    }
+}
+
+impl IStatement for EnumStatement {}
+
+pub fn create_enum_statement(
+    token: Token,
+    name: Identifier,
+    variants: Vec<EnumVariantDecl>,
+) -> EnumStatement {
+    EnumStatement { token, name, variants }
+}
@@ -0,0 +1,50 @@
+use crate::ast::ast::{INode, IStatement};
+
+use crate::ast::expr::Expression;
+use crate::ast::expressions::identifier::Identifier;
+use crate::token::token::Token;
+
+use super::block_statement::BlockStatement;
+
+#[derive(Debug, Clone)]
+pub struct ForStatement {
+    pub token: Token,
+    pub var: Identifier,
+    pub iterable: Box<Expression>,
+    pub block: BlockStatement,
+}
+
+impl INode for ForStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        format!(
+            "for {} in {} {{{}}}",
+            self.var.to_string(),
+            self.iterable.to_string(),
+            self.block.to_string()
+        )
+    }
+}
+
+impl IStatement for ForStatement {}
+
+pub fn create_for_statement(
+    token: Token,
+    var: Identifier,
+    iterable: Box<Expression>,
+    block: BlockStatement,
+) -> ForStatement {
+    ForStatement {
+        token,
+        var,
+        iterable,
+        block,
+    }
+}
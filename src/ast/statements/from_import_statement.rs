@@ -0,0 +1,62 @@
+use crate::ast::ast::{INode, IStatement};
+use crate::ast::expressions::identifier::Identifier;
+use crate::ast::statements::import_statement::module_path_to_string;
+
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub struct FromImportStatement {
+    pub token: Token,
+    pub path: Vec<Identifier>,
+    pub members: Vec<(Identifier, Option<Identifier>)>, // (成员名, 别名)
+}
+
+impl FromImportStatement {
+    // 模块路径, 如 a.b.c -> a/b/c
+    pub fn module_path(&self) -> String {
+        module_path_to_string(&self.path)
+    }
+}
+
+impl INode for FromImportStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        format!(
+            "from {} import {}",
+            self.path
+                .iter()
+                .map(|it| it.to_string())
+                .collect::<Vec<String>>()
+                .join("."),
+            self.members
+                .iter()
+                .map(|(name, alias)| match alias {
+                    Some(alias) => format!("{} as {}", name.to_string(), alias.to_string()),
+                    None => name.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl IStatement for FromImportStatement {}
+
+pub fn create_from_import_statement(
+    token: Token,
+    path: Vec<Identifier>,
+    members: Vec<(Identifier, Option<Identifier>)>,
+) -> FromImportStatement {
+    FromImportStatement {
+        token,
+        path,
+        members,
+    }
+}
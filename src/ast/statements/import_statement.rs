@@ -0,0 +1,59 @@
+use crate::ast::ast::{INode, IStatement};
+use crate::ast::expressions::identifier::Identifier;
+
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub struct ImportStatement {
+    pub token: Token,
+    pub path: Vec<Identifier>,
+    pub alias: Option<Identifier>,
+}
+
+impl ImportStatement {
+    // 模块路径, 如 a.b.c -> a/b/c
+    pub fn module_path(&self) -> String {
+        module_path_to_string(&self.path)
+    }
+}
+
+impl INode for ImportStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let path = self
+            .path
+            .iter()
+            .map(|it| it.to_string())
+            .collect::<Vec<String>>()
+            .join(".");
+
+        match &self.alias {
+            Some(alias) => format!("import {path} as {}", alias.to_string()),
+            None => format!("import {path}"),
+        }
+    }
+}
+
+impl IStatement for ImportStatement {}
+
+pub fn module_path_to_string(path: &[Identifier]) -> String {
+    path.iter()
+        .map(|it| it.value.clone())
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+pub fn create_import_statement(
+    token: Token,
+    path: Vec<Identifier>,
+    alias: Option<Identifier>,
+) -> ImportStatement {
+    ImportStatement { token, path, alias }
+}
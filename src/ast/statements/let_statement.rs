@@ -10,6 +10,7 @@ pub struct LetStatement {
     pub name: Identifier,
     pub value: Box<Expression>,
     pub type_hint: Option<Box<Expression>>,
+    pub readonly: bool, // const x = ... / readonly let x = ...
 }
 
 impl INode for LetStatement {
@@ -22,7 +23,12 @@ impl INode for LetStatement {
     }
 
     fn to_string(&self) -> String {
-        format!("let {} = {}", self.name.to_string(), self.value.to_string())
+        format!(
+            "{} {} = {}",
+            if self.readonly { "readonly let" } else { "let" },
+            self.name.to_string(),
+            self.value.to_string()
+        )
     }
 }
 
@@ -33,7 +39,7 @@ pub fn create_let_statement(
     name: Identifier,
     value: Box<Expression>,
 ) -> LetStatement {
-    LetStatement { token, name, value, type_hint: None }
+    LetStatement { token, name, value, type_hint: None, readonly: false }
 }
 
 pub fn create_let_statement_with_type(
@@ -42,6 +48,6 @@ pub fn create_let_statement_with_type(
     value: Box<Expression>,
     type_hint: Box<Expression>,
 ) -> LetStatement {
-    LetStatement { token, name, value, type_hint: Some(type_hint) }
+    LetStatement { token, name, value, type_hint: Some(type_hint), readonly: false }
 }
 
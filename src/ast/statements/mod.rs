@@ -1,9 +1,18 @@
 pub mod block_statement;
 pub mod class_statement;
 pub mod let_statement;
+pub mod destructuring_let_statement;
 pub mod while_statement;
 pub mod use_statement;
 pub mod break_statement;
 pub mod continue_statement;
 pub mod return_statement;
-pub mod test_print_statement;
\ No newline at end of file
+pub mod test_print_statement;
+pub mod for_statement;
+
+pub mod try_statement;
+pub mod import_statement;
+pub mod from_import_statement;
+pub mod visibility_statement;
+pub mod yield_statement;
+pub mod enum_statement;
\ No newline at end of file
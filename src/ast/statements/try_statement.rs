@@ -0,0 +1,60 @@
+use crate::ast::ast::{INode, IStatement};
+
+use crate::ast::expressions::identifier::Identifier;
+use crate::token::token::Token;
+
+use super::block_statement::BlockStatement;
+
+#[derive(Debug, Clone)]
+pub struct TryStatement {
+    pub token: Token,
+    pub block: BlockStatement,
+    pub catch_var: Option<Identifier>,
+    pub catch_block: Option<BlockStatement>,
+    pub finally_block: Option<BlockStatement>,
+}
+
+impl INode for TryStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let mut s = format!("try {{{}}}", self.block.to_string());
+
+        if let Some(catch_block) = &self.catch_block {
+            s.push_str(&match &self.catch_var {
+                Some(var) => format!(" catch {} {{{}}}", var.to_string(), catch_block.to_string()),
+                None => format!(" catch {{{}}}", catch_block.to_string()),
+            });
+        }
+
+        if let Some(finally_block) = &self.finally_block {
+            s.push_str(&format!(" finally {{{}}}", finally_block.to_string()));
+        }
+
+        s
+    }
+}
+
+impl IStatement for TryStatement {}
+
+pub fn create_try_statement(
+    token: Token,
+    block: BlockStatement,
+    catch_var: Option<Identifier>,
+    catch_block: Option<BlockStatement>,
+    finally_block: Option<BlockStatement>,
+) -> TryStatement {
+    TryStatement {
+        token,
+        block,
+        catch_var,
+        catch_block,
+        finally_block,
+    }
+}
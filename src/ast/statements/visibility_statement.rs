@@ -0,0 +1,31 @@
+use crate::ast::ast::{INode, IStatement};
+use crate::ast::stmt::Statement;
+use crate::token::token::Token;
+
+// public / private 修饰的类成员定义
+#[derive(Debug, Clone)]
+pub struct VisibilityStatement {
+    pub token: Token,
+    pub private: bool,
+    pub stmt: Box<Statement>,
+}
+
+impl INode for VisibilityStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        format!("{} {}", self.token.value, self.stmt.to_string())
+    }
+}
+
+impl IStatement for VisibilityStatement {}
+
+pub fn create_visibility_statement(token: Token, private: bool, stmt: Box<Statement>) -> VisibilityStatement {
+    VisibilityStatement { token, private, stmt }
+}
@@ -0,0 +1,29 @@
+use crate::ast::ast::{INode, IStatement};
+use crate::ast::expr::Expression;
+use crate::token::token::Token;
+
+#[derive(Debug, Clone)]
+pub struct YieldStatement {
+    pub value: Box<Expression>,
+    pub token: Token,
+}
+
+impl INode for YieldStatement {
+    fn token_literal(&self) -> String {
+        self.token.value.clone()
+    }
+
+    fn token(&self) -> Token {
+        self.token.clone()
+    }
+
+    fn to_string(&self) -> String {
+        format!("yield {}", self.value.to_string())
+    }
+}
+
+impl IStatement for YieldStatement {}
+
+pub fn create_yield_statement(token: Token, value: Box<Expression>) -> YieldStatement {
+    YieldStatement { token, value }
+}
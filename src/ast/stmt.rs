@@ -4,7 +4,7 @@ use crate::{
     ast::{
         ast::{ExpressionStatement, INode, IStatement},
         statements::{
-            block_statement::BlockStatement, break_statement::BreakStatement, class_statement::ClassStatement, continue_statement::ContinueStatement, let_statement::LetStatement, return_statement::ReturnStatement, test_print_statement::TestPrintStatement, use_statement::UseStatement, while_statement::WhileStatement
+            block_statement::BlockStatement, break_statement::BreakStatement, class_statement::ClassStatement, continue_statement::ContinueStatement, destructuring_let_statement::DestructuringLetStatement, enum_statement::EnumStatement, for_statement::ForStatement, from_import_statement::FromImportStatement, import_statement::ImportStatement, let_statement::LetStatement, return_statement::ReturnStatement, test_print_statement::TestPrintStatement, try_statement::TryStatement, use_statement::UseStatement, visibility_statement::VisibilityStatement, while_statement::WhileStatement, yield_statement::YieldStatement
         },
     },
     token::token::Token,
@@ -15,14 +15,22 @@ use crate::{
 pub enum Statement {
     BlockStatement,
     ClassStatement,
+    EnumStatement,
     LetStatement,
+    DestructuringLetStatement,
     UseStatement,
+    ImportStatement,
+    FromImportStatement,
     WhileStatement,
+    ForStatement,
+    TryStatement,
     ExpressionStatement,
     BreakStatement,
     ContinueStatement,
     ReturnStatement,
     TestPrintStatement,
+    VisibilityStatement,
+    YieldStatement,
 }
 
 macro_rules! auto_stmt {
@@ -30,14 +38,22 @@ macro_rules! auto_stmt {
         match $self {
             Statement::BlockStatement(stmt) => stmt.$method(),
             Statement::ClassStatement(stmt) => stmt.$method(),
+            Statement::EnumStatement(stmt) => stmt.$method(),
             Statement::LetStatement(stmt) => stmt.$method(),
+            Statement::DestructuringLetStatement(stmt) => stmt.$method(),
             Statement::UseStatement(stmt) => stmt.$method(),
+            Statement::ImportStatement(stmt) => stmt.$method(),
+            Statement::FromImportStatement(stmt) => stmt.$method(),
             Statement::WhileStatement(stmt) => stmt.$method(),
+            Statement::ForStatement(stmt) => stmt.$method(),
+            Statement::TryStatement(stmt) => stmt.$method(),
             Statement::ExpressionStatement(stmt) => stmt.$method(),
             Statement::BreakStatement(stmt) => stmt.$method(),
             Statement::ContinueStatement(stmt) => stmt.$method(),
             Statement::ReturnStatement(stmt) => stmt.$method(),
             Statement::TestPrintStatement(stmt) => stmt.$method(),
+            Statement::VisibilityStatement(stmt) => stmt.$method(),
+            Statement::YieldStatement(stmt) => stmt.$method(),
         }
     };
 }
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use num_traits::ToPrimitive;
+use once_cell::sync::Lazy;
+
+use crate::{
+    builtin::builtin_func::{ant_null, ant_some},
+    byte_code_vm::{constants::NONE_OBJ, vm::vm::Vm},
+    obj_enum::object::Object,
+    object::{
+        ant_array::AntArray,
+        ant_class::AntClass,
+        ant_int::AntInt,
+        ant_method::{Method, MethodType},
+        ant_native_function::create_ant_native_function,
+        object::IAntObject,
+    },
+};
+
+// 数组 / 字符串 / 哈希表 在 for 循环中使用的迭代器, 协议与 RANGE 相同
+pub static ITER: Lazy<AntClass> = Lazy::new(|| {
+    AntClass::from(("Iter", {
+        let next = |_vm: &mut Vm, args: Vec<std::rc::Rc<std::cell::RefCell<Object>>>| {
+            let mut o = args[0].borrow_mut();
+
+            let me = match &mut *o {
+                Object::AntClass(clazz) => clazz,
+                _ => return Err(format!("expected an class (self) got: {}", o.inspect())),
+            };
+
+            let next_index = match match me.map.get("next_index") {
+                Some(it) => it,
+                None => return Err(format!("object '{}' has no field 'next_index'", me.inspect())),
+            } {
+                Object::AntInt(int) => int.value.to_usize().unwrap_or(usize::MAX),
+                it => return Err(format!("expected an integer object, got: {}", it.inspect())),
+            };
+
+            let item = match match me.map.get("items") {
+                Some(it) => it,
+                None => return Err(format!("object '{}' has no field 'items'", me.inspect())),
+            } {
+                Object::AntArray(arr) => arr.items.get(next_index).cloned(),
+                it => return Err(format!("expected an array object, got: {}", it.inspect())),
+            };
+
+            match item {
+                Some(item) => {
+                    me.map.insert(
+                        "next_index".into(),
+                        Object::AntInt(AntInt::from(next_index + 1)),
+                    );
+
+                    Ok(Some(ant_some(item)))
+                }
+                None => Ok(Some(ant_null())),
+            }
+        };
+
+        let mut m = HashMap::new();
+
+        m.insert("next_index".into(), NONE_OBJ.clone());
+        m.insert("items".into(), NONE_OBJ.clone());
+
+        m.insert(
+            "next".into(),
+            Object::Method(Method {
+                me: None,
+                func: MethodType::NativeFunction(create_ant_native_function(None, next)),
+            }),
+        );
+
+        m
+    }))
+});
+
+pub fn create_iter(items: Vec<Object>) -> AntClass {
+    let mut it = ITER.clone();
+
+    it.map
+        .insert("next_index".into(), Object::AntInt(AntInt::from(0)));
+    it.map
+        .insert("items".into(), Object::AntArray(AntArray::from(items)));
+
+    it
+}
@@ -12,7 +12,9 @@ use crate::{
         ant_double::{AntDouble, sqrt_default},
         ant_native_function::create_ant_native_function,
         object::{DOUBLE, I64, IAntObject, INT},
+        type_hint::{TypeHint, TypeHintMap},
     },
+    type_hint, type_hint_map,
 };
 
 pub static MATH: Lazy<AntClass> = Lazy::new(|| {
@@ -51,7 +53,10 @@ pub static MATH: Lazy<AntClass> = Lazy::new(|| {
 
         m.insert(
             "sqrt".into(),
-            Object::AntNativeFunction(create_ant_native_function(None, sqrt)),
+            Object::AntNativeFunction(create_ant_native_function(
+                Some(type_hint_map!("0" => type_hint!(INT, I64, DOUBLE))),
+                sqrt,
+            )),
         );
 
         m
@@ -1,4 +1,5 @@
 pub mod range_class;
 pub mod result_class;
 pub mod option_class;
-pub mod math_class;
\ No newline at end of file
+pub mod math_class;
+pub mod iter_class;
\ No newline at end of file
@@ -14,7 +14,10 @@ use crate::{
     byte_code_vm::{
         utils::native_boolean_to_object,
         vm::{
-            eval_functions::eval_infix_operator::{eq_native_ref, gt_native_ref},
+            eval_functions::{
+                eval_infix_operator::{eq_native_ref, gt_native_ref},
+                eval_match::match_class,
+            },
             vm::Vm,
         },
     },
@@ -49,10 +52,12 @@ pub fn builtin_len(_vm: &mut Vm, args: Vec<Rc<RefCell<Object>>>) -> Result<Optio
     match &*obj {
         Object::AntArray(arr) => Ok(Some(Object::AntInt(AntInt::from(arr.items.len())))),
 
+        Object::AntTuple(tuple) => Ok(Some(Object::AntInt(AntInt::from(tuple.items.len())))),
+
         Object::AntString(s) => Ok(Some(Object::AntInt(AntInt::from(s.value.chars().count())))),
 
         _ => Err(format!(
-            "expected an array or string of function len, got: {}",
+            "expected an array, tuple or string of function len, got: {}",
             obj.inspect()
         )),
     }
@@ -64,6 +69,12 @@ pub fn builtin_copy(
 ) -> Result<Option<Object>, String> {
     let obj = args[0].borrow().clone();
 
+    if let Object::AntClass(clazz) = &obj
+        && clazz.interface
+    {
+        return Err(format!("cannot instantiate interface '{}'", clazz.name));
+    }
+
     Ok(Some(obj))
 }
 
@@ -170,6 +181,64 @@ pub fn builtin_create_method(
     }
 }
 
+// 装饰器 #[unchecked]: 返回跳过类型注解检查的函数副本, 用于热点代码
+pub fn builtin_unchecked(
+    _vm: &mut Vm,
+    args: Vec<Rc<RefCell<Object>>>,
+) -> Result<Option<Object>, String> {
+    let borrowed = args[0].borrow();
+
+    match &*borrowed {
+        Object::Closure(cl) => {
+            let mut cl = cl.clone();
+            cl.func.unchecked = true;
+
+            Ok(Some(Object::Closure(cl)))
+        }
+        Object::Method(Method { me, func: MethodType::Closure(cl) }) => {
+            let mut cl = cl.clone();
+            cl.func.unchecked = true;
+
+            Ok(Some(Object::Method(Method {
+                me: me.clone(),
+                func: MethodType::Closure(cl),
+            })))
+        }
+        _ => Err(format!("cannot make {} unchecked", borrowed.inspect())),
+    }
+}
+
+// implements(obj, Iface): obj 继承自接口, 或定义了接口中所有未定义的成员
+pub fn builtin_implements(
+    _vm: &mut Vm,
+    args: Vec<Rc<RefCell<Object>>>,
+) -> Result<Option<Object>, String> {
+    if args.len() != 2 {
+        return Err(format!("implements expected 2 arguments, got: {}", args.len()));
+    }
+
+    let obj = args[0].borrow();
+    let iface = args[1].borrow();
+
+    let iface = match &*iface {
+        Object::AntClass(it) if it.interface => it,
+        it => return Err(format!("expected an interface, got: {}", it.inspect())),
+    };
+
+    // 继承了接口, 或定义了接口声明的所有成员; 没有声明成员的 (标记) 接口只能通过继承实现
+    let members = iface.uninit_members();
+
+    let implemented = match_class(&obj, &iface.name)
+        || match &*obj {
+            Object::AntClass(clazz) if !members.is_empty() => {
+                members.iter().all(|(field, _)| clazz.defines(field))
+            }
+            _ => false,
+        };
+
+    Ok(Some(native_boolean_to_object(implemented)))
+}
+
 pub fn builtin_range(
     _vm: &mut Vm,
     args: Vec<Rc<RefCell<Object>>>,
@@ -190,7 +259,7 @@ pub fn builtin_range(
 }
 
 pub fn builtin_panic(
-    _vm: &mut Vm,
+    vm: &mut Vm,
     args: Vec<Rc<RefCell<Object>>>,
 ) -> Result<Option<Object>, String> {
     let err = args[0].borrow().clone();
@@ -198,6 +267,8 @@ pub fn builtin_panic(
         return Err(format!("expected an string, got: {}", err.inspect()));
     }
 
+    vm.error_message = Some(err.inspect());
+
     Err(format!("panic: \"{}\"", err.inspect()))
 }
 
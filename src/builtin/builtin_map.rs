@@ -2,10 +2,16 @@ use lazy_static::lazy_static;
 
 use crate::{
     builtin::{builtin_classes::math_class::MATH, builtin_func::{
-        ant_null, builtin_clear, builtin_copy, builtin_create_method, builtin_double, builtin_err, builtin_force_exit, builtin_id, builtin_int, builtin_len, builtin_now, builtin_obj_info, builtin_ok, builtin_panic, builtin_print, builtin_range, builtin_shell, builtin_some, builtin_sorted, builtin_str
+        ant_null, builtin_clear, builtin_copy, builtin_create_method, builtin_double, builtin_err, builtin_force_exit, builtin_id, builtin_implements, builtin_int, builtin_len, builtin_now, builtin_obj_info, builtin_ok, builtin_panic, builtin_print, builtin_range, builtin_shell, builtin_some, builtin_sorted, builtin_str, builtin_unchecked
     }},
     obj_enum::object::Object,
-    object::{ant_int::AntInt, ant_native_function::create_ant_native_function},
+    object::{
+        ant_int::AntInt,
+        ant_native_function::create_ant_native_function,
+        object::{ARRAY, INT},
+        type_hint::{TypeHint, TypeHintMap},
+    },
+    type_hint, type_hint_map,
 };
 
 lazy_static! {
@@ -62,9 +68,22 @@ lazy_static! {
             Object::AntNativeFunction(create_ant_native_function(None, builtin_create_method)),
         );
 
+        m.insert(
+            "unchecked".into(),
+            Object::AntNativeFunction(create_ant_native_function(None, builtin_unchecked)),
+        );
+
+        m.insert(
+            "implements".into(),
+            Object::AntNativeFunction(create_ant_native_function(None, builtin_implements)),
+        );
+
         m.insert(
             "range".into(),
-            Object::AntNativeFunction(create_ant_native_function(None, builtin_range)),
+            Object::AntNativeFunction(create_ant_native_function(
+                Some(type_hint_map!("0" => type_hint!(INT))),
+                builtin_range,
+            )),
         );
 
         m.insert(
@@ -89,7 +108,10 @@ lazy_static! {
 
         m.insert(
             "sorted".into(),
-            Object::AntNativeFunction(create_ant_native_function(None, builtin_sorted)),
+            Object::AntNativeFunction(create_ant_native_function(
+                Some(type_hint_map!("0" => type_hint!(ARRAY))),
+                builtin_sorted,
+            )),
         );
         
         m.insert(
@@ -148,6 +170,8 @@ lazy_static! {
             "Ok".into(),
             "Err".into(),
             "Int".into(),
+            "unchecked".into(),
+            "implements".into(),
         ]
     };
 }
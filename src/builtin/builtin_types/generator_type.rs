@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    builtin::builtin_func::{ant_null, ant_some},
+    byte_code_vm::vm::{generator_utils::resume_generator, vm::Vm},
+    obj_enum::object::Object,
+    object::{
+        ant_method::{Method, MethodType},
+        ant_native_function::create_ant_native_function,
+        object::IAntObject,
+    },
+};
+
+// 与 Range.next 一致: 返回 Some(value), 生成器结束后返回 Null
+fn next(
+    vm: &mut Vm,
+    args: Vec<std::rc::Rc<std::cell::RefCell<Object>>>,
+) -> Result<Option<Object>, String> {
+    match resume_generator(vm, args[0].clone())? {
+        Some(value) => Ok(Some(ant_some(value.borrow().clone()))),
+        None => Ok(Some(ant_null())),
+    }
+}
+
+// 生成器结束后返回 Some(返回值), 未结束时返回 Null
+fn result(
+    _vm: &mut Vm,
+    args: Vec<std::rc::Rc<std::cell::RefCell<Object>>>,
+) -> Result<Option<Object>, String> {
+    let state = match &*args[0].borrow() {
+        Object::AntGenerator(it) => it.state.clone(),
+        it => return Err(format!("expected a generator, got: {}", it.inspect())),
+    };
+
+    match &state.borrow().return_value {
+        Some(value) => Ok(Some(ant_some(value.borrow().clone()))),
+        _ => Ok(Some(ant_null())),
+    }
+}
+
+pub static GENERATOR_MEMBERS: Lazy<HashMap<String, Object>> = Lazy::new(|| {
+    let mut m = HashMap::new();
+
+    m.insert(
+        "next".to_string(),
+        Object::Method(Method {
+            me: None,
+            func: MethodType::NativeFunction(create_ant_native_function(None, next)),
+        }),
+    );
+
+    m.insert(
+        "result".to_string(),
+        Object::Method(Method {
+            me: None,
+            func: MethodType::NativeFunction(create_ant_native_function(None, result)),
+        }),
+    );
+
+    m
+});
@@ -3,12 +3,15 @@ use std::collections::HashMap;
 use once_cell::sync::Lazy;
 
 use crate::{
-    builtin::builtin_types::{array_type::ARRAY_MEMBERS, int_type::INT_MEMBERS},
+    builtin::builtin_types::{
+        array_type::ARRAY_MEMBERS, generator_type::GENERATOR_MEMBERS, int_type::INT_MEMBERS,
+    },
     obj_enum::object::Object,
-    object::object::{ARRAY, INT},
+    object::object::{ARRAY, GENERATOR, INT},
 };
 
 pub mod array_type;
+pub mod generator_type;
 pub mod int_type;
 
 pub static BUILTIN_TYPE_MAP: Lazy<HashMap<String, HashMap<String, Object>>> = Lazy::new(|| {
@@ -16,6 +19,7 @@ pub static BUILTIN_TYPE_MAP: Lazy<HashMap<String, HashMap<String, Object>>> = La
 
     m.insert(INT.to_string(), INT_MEMBERS.clone());
     m.insert(ARRAY.to_string(), ARRAY_MEMBERS.clone());
+    m.insert(GENERATOR.to_string(), GENERATOR_MEMBERS.clone());
 
     m
 });
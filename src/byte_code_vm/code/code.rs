@@ -13,6 +13,7 @@ pub const OP_ADD: u8 = 1;
 pub const OP_SUBTRACT: u8 = 2;
 pub const OP_MULTIPLY: u8 = 3;
 pub const OP_DIVIDE: u8 = 4;
+pub const OP_LT: u8 = 5;
 pub const OP_GT: u8 = 6;
 pub const OP_EQ: u8 = 7;
 pub const OP_NOTEQ: u8 = 8;
@@ -39,31 +40,100 @@ pub const OP_GET_BUILTIN: u8 = 28;
 pub const OP_CURRENT_CLOSURE: u8 = 29;
 pub const OP_NONE: u8 = 30;
 pub const OP_HASH: u8 = 31;
-pub const OP_AND: u8 = 32;
-pub const OP_OR: u8 = 33;
+pub const OP_JUMP_IF_FALSE_OR_POP: u8 = 32;
+pub const OP_JUMP_IF_TRUE_OR_POP: u8 = 33;
 pub const OP_GET_FIELD: u8 = 34;
 pub const OP_SET_FIELD: u8 = 35;
 pub const OP_CLASS: u8 = 36;
 pub const OP_LOAD_MODULE: u8 = 37;
 pub const OP_TEST_PRINT: u8 = 38;
+pub const OP_GET_ITER: u8 = 39;
+pub const OP_ITER_NEXT: u8 = 40;
+pub const OP_SETUP_TRY: u8 = 41;
+pub const OP_POP_TRY: u8 = 42;
+pub const OP_THROW: u8 = 43;
+pub const OP_IMPORT_MEMBER: u8 = 44;
+pub const OP_INHERIT: u8 = 45;
+pub const OP_GET_SUPER: u8 = 46;
+pub const OP_GET_CLASS_MEMBER: u8 = 47;
+pub const OP_MOD: u8 = 48;
+pub const OP_FLOOR_DIV: u8 = 49;
+pub const OP_POW: u8 = 50;
+pub const OP_BIT_AND: u8 = 51;
+pub const OP_BIT_OR: u8 = 52;
+pub const OP_BIT_XOR: u8 = 53;
+pub const OP_SHL: u8 = 54;
+pub const OP_SHR: u8 = 55;
+pub const OP_BIT_NOT: u8 = 56;
+pub const OP_LE: u8 = 57;
+pub const OP_GE: u8 = 58;
+pub const OP_DUP: u8 = 59;
+pub const OP_REVERSE: u8 = 60;
+pub const OP_FORMAT_VALUE: u8 = 61;
+pub const OP_BUILD_STRING: u8 = 62;
+pub const OP_MATCH_EQ: u8 = 63;
+pub const OP_MATCH_ARRAY: u8 = 64;
+pub const OP_MATCH_HASH: u8 = 65;
+pub const OP_MATCH_CLASS: u8 = 66;
+pub const OP_MATCH_HAS_KEY: u8 = 67;
+pub const OP_MATCH_FAIL: u8 = 68;
+pub const OP_CHECK_ARRAY: u8 = 69;
+pub const OP_CHECK_HASH: u8 = 70;
+pub const OP_CHECK_INSTANCE: u8 = 71;
+pub const OP_ARRAY_REST: u8 = 72;
+pub const OP_TUPLE: u8 = 73;
+pub const OP_CALL_KW: u8 = 74;
+pub const OP_JUMP_IF_BOUND: u8 = 75;
+pub const OP_SET_FREE: u8 = 76;
+pub const OP_GET_SELF_FIELD: u8 = 77;
+pub const OP_SET_SELF_FIELD: u8 = 78;
+pub const OP_YIELD: u8 = 79;
+pub const OP_ENUM_VALUE: u8 = 80;
+pub const OP_ENUM: u8 = 81;
+pub const OP_MATCH_VARIANT: u8 = 82;
 
 pub const INFIX_OPERATOR_TO_OPCODE: phf::Map<&'static str, OpCode> = phf::phf_map! {
     "+" => OP_ADD,
     "-" => OP_SUBTRACT,
     "*" => OP_MULTIPLY,
     "/" => OP_DIVIDE,
+    "%" => OP_MOD,
+    "~/" => OP_FLOOR_DIV,
+    "**" => OP_POW,
+    "&" => OP_BIT_AND,
+    "|" => OP_BIT_OR,
+    "^" => OP_BIT_XOR,
+    "<<" => OP_SHL,
+    ">>" => OP_SHR,
+    "<" => OP_LT,
     ">" => OP_GT,
+    "<=" => OP_LE,
+    ">=" => OP_GE,
     "==" => OP_EQ,
     "!=" => OP_NOTEQ,
-    "and" => OP_AND,
-    "or" => OP_OR,
 };
 
 pub const PREFIX_OPERATOR_TO_OPCODE: phf::Map<&'static str, OpCode> = phf::phf_map! {
     "-" => OP_MINUS,
     "!" => OP_BANG,
+    "~" => OP_BIT_NOT,
 };
 
+// 运算符对应的符号, 用于错误信息
+pub fn infix_operator_symbol(op: OpCode) -> &'static str {
+    operator_symbol(&INFIX_OPERATOR_TO_OPCODE, op)
+}
+
+pub fn prefix_operator_symbol(op: OpCode) -> &'static str {
+    operator_symbol(&PREFIX_OPERATOR_TO_OPCODE, op)
+}
+
+fn operator_symbol(map: &phf::Map<&'static str, OpCode>, op: OpCode) -> &'static str {
+    map.entries()
+        .find(|(_, it)| **it == op)
+        .map_or("?", |(symbol, _)| symbol)
+}
+
 #[derive(Debug, Clone)]
 pub struct Definition {
     pub name: String,
@@ -88,6 +158,7 @@ lazy_static! {
         m.insert(OP_SUBTRACT, Definition::new("OpSubtract".into(), vec![]));
         m.insert(OP_MULTIPLY, Definition::new("OpMultiply".into(), vec![]));
         m.insert(OP_DIVIDE, Definition::new("OpDivide".into(), vec![]));
+        m.insert(OP_LT, Definition::new("OpLessThan".into(), vec![]));
         m.insert(OP_GT, Definition::new("OpGreaterThan".into(), vec![]));
         m.insert(OP_EQ, Definition::new("OpEqual".into(), vec![]));
         m.insert(OP_NOTEQ, Definition::new("OpNotEqual".into(), vec![]));
@@ -131,14 +202,58 @@ lazy_static! {
             Definition::new("OpCurrentClosure".into(), vec![]),
         );
         m.insert(OP_NONE, Definition::new("OpNone".into(), vec![]));
-        m.insert(OP_AND, Definition::new("OpAnd".into(), vec![]));
-        m.insert(OP_OR, Definition::new("OpOr".into(), vec![]));
+        m.insert(OP_JUMP_IF_FALSE_OR_POP, Definition::new("OpJumpIfFalseOrPop".into(), vec![2]));
+        m.insert(OP_JUMP_IF_TRUE_OR_POP, Definition::new("OpJumpIfTrueOrPop".into(), vec![2]));
         m.insert(OP_HASH, Definition::new("OpHash".into(), vec![2]));
         m.insert(OP_GET_FIELD, Definition::new("OpGetField".into(), vec![2]));
         m.insert(OP_SET_FIELD, Definition::new("OpSetField".into(), vec![2]));
-        m.insert(OP_CLASS, Definition::new("OpClass".into(), vec![2, 2]));
+        m.insert(OP_CLASS, Definition::new("OpClass".into(), vec![2, 2, 2, 2, 1]));
         m.insert(OP_LOAD_MODULE, Definition::new("OpLoadModule".into(), vec![2]));
         m.insert(OP_TEST_PRINT, Definition::new("OpTestPrint".into(), vec![]));
+        m.insert(OP_GET_ITER, Definition::new("OpGetIter".into(), vec![]));
+        m.insert(OP_ITER_NEXT, Definition::new("OpIterNext".into(), vec![2]));
+        m.insert(OP_SETUP_TRY, Definition::new("OpSetupTry".into(), vec![2]));
+        m.insert(OP_POP_TRY, Definition::new("OpPopTry".into(), vec![]));
+        m.insert(OP_THROW, Definition::new("OpThrow".into(), vec![]));
+        m.insert(OP_IMPORT_MEMBER, Definition::new("OpImportMember".into(), vec![2]));
+        m.insert(OP_INHERIT, Definition::new("OpInherit".into(), vec![]));
+        m.insert(OP_GET_SUPER, Definition::new("OpGetSuper".into(), vec![2]));
+        m.insert(OP_GET_CLASS_MEMBER, Definition::new("OpGetClassMember".into(), vec![2]));
+        m.insert(OP_MOD, Definition::new("OpMod".into(), vec![]));
+        m.insert(OP_FLOOR_DIV, Definition::new("OpFloorDiv".into(), vec![]));
+        m.insert(OP_POW, Definition::new("OpPow".into(), vec![]));
+        m.insert(OP_BIT_AND, Definition::new("OpBitAnd".into(), vec![]));
+        m.insert(OP_BIT_OR, Definition::new("OpBitOr".into(), vec![]));
+        m.insert(OP_BIT_XOR, Definition::new("OpBitXor".into(), vec![]));
+        m.insert(OP_SHL, Definition::new("OpShl".into(), vec![]));
+        m.insert(OP_SHR, Definition::new("OpShr".into(), vec![]));
+        m.insert(OP_BIT_NOT, Definition::new("OpBitNot".into(), vec![]));
+        m.insert(OP_LE, Definition::new("OpLessEqual".into(), vec![]));
+        m.insert(OP_GE, Definition::new("OpGreaterEqual".into(), vec![]));
+        m.insert(OP_DUP, Definition::new("OpDup".into(), vec![1]));
+        m.insert(OP_REVERSE, Definition::new("OpReverse".into(), vec![1]));
+        m.insert(OP_FORMAT_VALUE, Definition::new("OpFormatValue".into(), vec![2]));
+        m.insert(OP_BUILD_STRING, Definition::new("OpBuildString".into(), vec![2]));
+        m.insert(OP_MATCH_EQ, Definition::new("OpMatchEq".into(), vec![]));
+        m.insert(OP_MATCH_ARRAY, Definition::new("OpMatchArray".into(), vec![2]));
+        m.insert(OP_MATCH_HASH, Definition::new("OpMatchHash".into(), vec![]));
+        m.insert(OP_MATCH_CLASS, Definition::new("OpMatchClass".into(), vec![2]));
+        m.insert(OP_MATCH_HAS_KEY, Definition::new("OpMatchHasKey".into(), vec![]));
+        m.insert(OP_MATCH_FAIL, Definition::new("OpMatchFail".into(), vec![]));
+        m.insert(OP_CHECK_ARRAY, Definition::new("OpCheckArray".into(), vec![2, 1]));
+        m.insert(OP_CHECK_HASH, Definition::new("OpCheckHash".into(), vec![2]));
+        m.insert(OP_CHECK_INSTANCE, Definition::new("OpCheckInstance".into(), vec![2]));
+        m.insert(OP_ARRAY_REST, Definition::new("OpArrayRest".into(), vec![2]));
+        m.insert(OP_TUPLE, Definition::new("OpTuple".into(), vec![2]));
+        m.insert(OP_CALL_KW, Definition::new("OpCallKw".into(), vec![1, 2]));
+        m.insert(OP_JUMP_IF_BOUND, Definition::new("OpJumpIfBound".into(), vec![2, 2]));
+        m.insert(OP_SET_FREE, Definition::new("OpSetFree".into(), vec![2]));
+        m.insert(OP_GET_SELF_FIELD, Definition::new("OpGetSelfField".into(), vec![2, 2]));
+        m.insert(OP_SET_SELF_FIELD, Definition::new("OpSetSelfField".into(), vec![2, 2]));
+        m.insert(OP_YIELD, Definition::new("OpYield".into(), vec![]));
+        m.insert(OP_ENUM_VALUE, Definition::new("OpEnumValue".into(), vec![2]));
+        m.insert(OP_ENUM, Definition::new("OpEnum".into(), vec![2]));
+        m.insert(OP_MATCH_VARIANT, Definition::new("OpMatchVariant".into(), vec![2]));
 
         m
     };
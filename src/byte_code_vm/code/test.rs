@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::byte_code_vm::code::code::{
-        OP_ADD, OP_CALL, OP_CLOSURE, OP_CONSTANTS, OpCode, instruction_to_str, lookup, make,
+        OP_ADD, OP_CALL, OP_CLOSURE, OP_CONSTANTS, OP_FLOOR_DIV, OP_MINUS, OP_SUBTRACT, OpCode,
+        infix_operator_symbol, instruction_to_str, lookup, make, prefix_operator_symbol,
         read_operands,
     };
 
@@ -147,4 +148,12 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_operator_symbol() {
+        assert_eq!(infix_operator_symbol(OP_ADD), "+");
+        assert_eq!(infix_operator_symbol(OP_SUBTRACT), "-");
+        assert_eq!(infix_operator_symbol(OP_FLOOR_DIV), "~/");
+        assert_eq!(prefix_operator_symbol(OP_MINUS), "-");
+    }
 }
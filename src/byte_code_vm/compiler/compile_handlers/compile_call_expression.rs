@@ -1,6 +1,8 @@
 use crate::{
     ast::{ast::Node, expr::Expression},
-    byte_code_vm::{code::code::OP_CALL, compiler::compiler::{CompileError, Compiler}},
+    byte_code_vm::{code::code::{OP_CALL, OP_CALL_KW}, compiler::compiler::{CompileError, Compiler}},
+    obj_enum::object::Object,
+    object::{ant_string::AntString, ant_tuple::AntTuple},
 };
 
 pub fn compile_call_expression(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
@@ -28,7 +30,27 @@ pub fn compile_call_expression(compiler: &mut Compiler, node: Node) -> Result<()
         }
     }
 
-    compiler.emit(OP_CALL, vec![args_len as u16]);
+    if call_expr.keyword_args.is_empty() {
+        compiler.emit(OP_CALL, vec![args_len as u16]);
+        return Ok(());
+    }
+
+    // 关键字实参的值依次压栈, 实参名作为常量元组
+    let mut names = vec![];
+
+    for (name, arg) in call_expr.keyword_args {
+        if let Err(msg) = compiler.compile_expr(*arg) {
+            return Err(CompileError::from_none_token(
+                format!("error compile keyword arg '{name}': \n{msg}")
+            ));
+        }
+
+        names.push(Object::AntString(AntString::new(name)));
+    }
+
+    let names_index = compiler.add_constant(Object::AntTuple(AntTuple::from(names)));
+
+    compiler.emit(OP_CALL_KW, vec![args_len as u16, names_index as u16]);
 
     Ok(())
 }
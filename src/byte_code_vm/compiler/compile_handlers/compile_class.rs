@@ -1,11 +1,13 @@
+use std::rc::Rc;
+
 #[cfg(feature = "debug")]
 use crate::object::id_counter::next_id;
 use crate::{
     ast::{ast::Node, stmt::Statement},
     byte_code_vm::{
         code::code::{
-            OP_CALL, OP_CLASS, OP_CLOSURE, OP_CONSTANTS, OP_POP, OP_RETURN_VALUE, OP_SET_GLOBAL,
-            OP_SET_LOCAL,
+            OP_CALL, OP_CLASS, OP_CLOSURE, OP_CONSTANTS, OP_INHERIT, OP_POP, OP_RETURN_VALUE,
+            OP_SET_GLOBAL, OP_SET_LOCAL,
         },
         compiler::{
             compiler::{CompileError, Compiler},
@@ -14,7 +16,7 @@ use crate::{
         scope_info::ScopeInfo,
     },
     obj_enum::object::Object,
-    object::{ant_compiled_function::CompiledFunction, ant_string::AntString},
+    object::{ant_compiled_function::CompiledFunction, ant_string::AntString, ant_tuple::AntTuple},
 };
 
 pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
@@ -26,7 +28,7 @@ pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileE
         _ => unreachable!(),
     };
 
-    let symbol = compiler.symbol_table.borrow_mut().define(&clazz.name.value);
+    let symbol = compiler.define_symbol(&clazz.name.value, Some(&clazz.name.token))?;
 
     compiler.enter_scope(ScopeInfo {
         file_name: clazz.token.file.as_str().into(),
@@ -37,8 +39,25 @@ pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileE
         .into(),
     });
 
+    // 记录父类, 供类中方法的 super 使用
+    compiler
+        .class_bases
+        .push(clazz.base.as_ref().map(|base| base.value.clone()));
+    compiler.class_names.push(clazz.name.value.clone());
+
+    let outer_interface_scope = std::mem::replace(
+        &mut compiler.interface_scope,
+        clazz.interface.then_some(compiler.scope_index),
+    );
+
     // 编译类中的代码
-    if let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(clazz.block)) {
+    let result = compiler.compile_stmt(Statement::BlockStatement(clazz.block));
+
+    compiler.class_bases.pop();
+    compiler.class_names.pop();
+    compiler.interface_scope = outer_interface_scope;
+
+    if let Err(msg) = result {
         return Err(CompileError::from_none_token(format!(
             "error compile class: \n{msg}"
         )));
@@ -50,6 +69,20 @@ pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileE
     // (name, symbol) k, v
     let symbols_len = symbols.len() * 2;
 
+    // readonly 字段名
+    let readonly_fields = symbols
+        .iter()
+        .filter(|(_, symbol)| symbol.readonly && symbol.scope == SymbolScope::Local)
+        .map(|(name, _)| Object::AntString(AntString::new(name.clone())))
+        .collect::<Vec<_>>();
+
+    // private 成员名
+    let private_fields = symbols
+        .iter()
+        .filter(|(_, symbol)| symbol.private && symbol.scope == SymbolScope::Local)
+        .map(|(name, _)| Object::AntString(AntString::new(name.clone())))
+        .collect::<Vec<_>>();
+
     // 将字段名 (原 name) 压栈, 之后将符号所代表的值压栈
     for (name, symbol) in symbols {
         let field = Object::AntString(AntString::new(name));
@@ -64,11 +97,39 @@ pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileE
     let name_constant_index =
         compiler.add_constant(Object::AntString(clazz.name.value.clone().into()));
 
+    let readonly_constant_index =
+        compiler.add_constant(Object::AntTuple(AntTuple::from(readonly_fields)));
+    let private_constant_index =
+        compiler.add_constant(Object::AntTuple(AntTuple::from(private_fields)));
+
     compiler.emit(
         OP_CLASS,
-        vec![symbols_len as u16, name_constant_index as u16],
+        vec![
+            symbols_len as u16,
+            name_constant_index as u16,
+            readonly_constant_index as u16,
+            private_constant_index as u16,
+            clazz.interface as u16,
+        ],
     );
 
+    // 设置父类
+    if let Some(base) = &clazz.base {
+        let base_symbol = match compiler.symbol_table.borrow_mut().resolve(&base.value) {
+            Some(it) => it,
+            None => {
+                return Err(CompileError::from(
+                    format!("undefined parent class: {}.", base.value),
+                    Some(base.token.clone()),
+                ));
+            }
+        };
+
+        compiler.load_symbol(&base_symbol);
+
+        compiler.emit(OP_INHERIT, vec![]);
+    }
+
     if compiler.last_instruction_is(OP_POP) {
         compiler.remove_last_pop_to(OP_RETURN_VALUE, &vec![]);
     }
@@ -83,17 +144,17 @@ pub fn compile_class(compiler: &mut Compiler, node: Node) -> Result<(), CompileE
     // 离开作用域
     let ins = compiler.leave_scope();
     
-    // 加载自由变量
-    for symbol in &free_symbols {  
-        compiler.load_symbol(symbol);  
-    }
-  
     let compiled_function = CompiledFunction {
         #[cfg(feature = "debug")]
         id: next_id(),
         instructions: ins.borrow().clone().into(),
         local_count: symbols_len / 2,
         param_count: 0,
+        params: Rc::default(),
+        return_type: None,
+        unchecked: false,
+        is_generator: false,
+        captures: Compiler::capture_symbols(&free_symbols),
         scope_info: ScopeInfo {
             file_name: clazz.token.file.as_str().into(),
             scope_name: format!(
@@ -0,0 +1,172 @@
+use crate::{
+    ast::{ast::{INode, Node}, expr::Expression},
+    byte_code_vm::{
+        code::code::{
+            INFIX_OPERATOR_TO_OPCODE, OP_DUP, OP_GET_CLASS_MEMBER, OP_INDEX,
+            OP_REVERSE, OP_SET_FIELD, OP_SET_INDEX,
+        },
+        compiler::compiler::{CompileError, Compiler},
+    },
+};
+
+/*
+复合赋值 (a op= b), 赋值目标的容器与索引只求值一次:
+    标识符:   GET a, b, op, SET a
+    索引:     arr, index, DUP 2, INDEX, b, op, REVERSE 3, SET_INDEX
+    对象成员: obj, DUP 1, GET_FIELD f, b, op, REVERSE 2, SET_FIELD f
+*/
+pub fn compile_compound_assignment(
+    compiler: &mut Compiler,
+    node: Node,
+) -> Result<(), CompileError> {
+    let assign_expr = match match node {
+        Node::Expression(expr) => expr,
+        _ => unreachable!()
+    } {
+        Expression::AssignmentExpression(it) => it,
+        _ => unreachable!()
+    };
+
+    let op = match assign_expr
+        .token
+        .value
+        .strip_suffix('=')
+        .and_then(|op| INFIX_OPERATOR_TO_OPCODE.get(op))
+    {
+        Some(it) => *it,
+        None => {
+            return Err(CompileError::from(
+                format!("unknown assignment operator: {}", assign_expr.token.value),
+                Some(assign_expr.token),
+            ));
+        }
+    };
+
+    macro_rules! compile_value_and_op {
+        () => {
+            if let Err(msg) = compiler.compile_expr(*assign_expr.value) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile assignment value: \n{msg}"
+                )));
+            }
+
+            compiler.emit(op, vec![]);
+        };
+    }
+
+    match *assign_expr.left {
+        Expression::Identifier(ident) => {
+            let symbol = match compiler.symbol_table.borrow_mut().resolve(&ident.value) {
+                Some(it) => it,
+                None => {
+                    return Err(CompileError::from(
+                        format!("undefined identifier: {}.", ident.value),
+                        Some(ident.token),
+                    ));
+                }
+            };
+
+            if symbol.readonly {
+                return Err(CompileError::from(
+                    format!("cannot assign to readonly variable: {}.", ident.value),
+                    Some(ident.token),
+                ));
+            }
+
+            compiler.load_symbol(&symbol);
+
+            compile_value_and_op!();
+
+            compiler.store_symbol(&symbol);
+        }
+
+        Expression::IndexExpression(index_expr) => {
+            if let Err(msg) = compiler.compile_expr(*index_expr.expr) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile target: \n{msg}"
+                )));
+            }
+
+            if let Err(msg) = compiler.compile_expr(*index_expr.index) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile index: \n{msg}"
+                )));
+            }
+
+            compiler.emit(OP_DUP, vec![2]);
+            compiler.emit(OP_INDEX, vec![]);
+
+            compile_value_and_op!();
+
+            // 调整为 OP_SET_INDEX 需要的顺序: 值, 索引, 目标
+            compiler.emit(OP_REVERSE, vec![3]);
+            compiler.emit(OP_SET_INDEX, vec![]);
+        }
+
+        Expression::ObjectMemberExpression(obj_member) => {
+            let field = match *obj_member.right {
+                Expression::Identifier(it) => it,
+                it => {
+                    return Err(CompileError::from(
+                        String::from("expected an identifier of object member"),
+                        Some(it.token()),
+                    ));
+                }
+            };
+
+            let field_index = compiler.add_field(&field.value) as u16;
+            let self_class = compiler.self_access_class(&obj_member.left);
+
+            if let Err(msg) = compiler.compile_expr(*obj_member.left) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile object: \n{msg}"
+                )));
+            }
+
+            compiler.emit(OP_DUP, vec![1]);
+            compiler.emit_get_field(field_index, self_class);
+
+            compile_value_and_op!();
+
+            compiler.emit(OP_REVERSE, vec![2]);
+            compiler.emit_set_field(field_index, self_class);
+        }
+
+        Expression::ClassMemberExpression(class_member) => {
+            let field = match *class_member.right {
+                Expression::Identifier(it) => it,
+                it => {
+                    return Err(CompileError::from(
+                        String::from("expected an identifier of class member"),
+                        Some(it.token()),
+                    ));
+                }
+            };
+
+            let field_index = compiler.add_field(&field.value) as u16;
+
+            if let Err(msg) = compiler.compile_expr(*class_member.left) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile class: \n{msg}"
+                )));
+            }
+
+            compiler.emit(OP_DUP, vec![1]);
+            compiler.emit(OP_GET_CLASS_MEMBER, vec![field_index]);
+
+            compile_value_and_op!();
+
+            compiler.emit(OP_REVERSE, vec![2]);
+            compiler.emit(OP_SET_FIELD, vec![field_index]);
+        }
+
+        _ => {
+            return Err(CompileError::from(
+                String::from("cannot assign to literal here."),
+                Some(assign_expr.left.token()),
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
 use crate::{
     ast::{
-        ast::Node, expr::Expression, expressions::call_expression::CallExpression, stmt::Statement,
+        ast::{ExpressionStatement, Node},
+        expr::Expression,
+        expressions::call_expression::CallExpression,
+        stmt::Statement,
     },
     byte_code_vm::{
         code::code::{OP_CALL, OP_POP, OP_SET_GLOBAL, OP_SET_LOCAL},
         compiler::{
+            compile_handlers::compile_visibility_statement::compile_visibility_statement,
             compiler::{CompileError, Compiler},
             symbol_table::symbol_table::SymbolScope,
         },
@@ -33,10 +37,7 @@ fn compile_decorator_call(
             )));
         }
 
-        let symbol = compiler
-            .symbol_table
-            .borrow_mut()
-            .define(&let_stmt.name.value);
+        let symbol = compiler.define_symbol(&let_stmt.name.value, Some(&let_stmt.name.token))?;
 
         compiler.emit(
             if symbol.scope == SymbolScope::Global {
@@ -143,10 +144,7 @@ fn compile_decorator_ident(
     }
 
     if let Statement::LetStatement(let_stmt) = to_decorate {
-        let symbol = compiler
-            .symbol_table
-            .borrow_mut()
-            .define(&let_stmt.name.value);
+        let symbol = compiler.define_symbol(&let_stmt.name.value, Some(&let_stmt.name.token))?;
 
         if let Err(msg) = compiler.compile_expr(*let_stmt.value) {
             return Err(CompileError::from_none_token(format!(
@@ -225,7 +223,7 @@ fn compile_decorator_ident(
 }
 
 pub fn compile_decorator(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
-    let decorator = match match node {
+    let mut decorator = match match node {
         Node::Expression(expr) => expr,
         _ => unreachable!(),
     } {
@@ -233,6 +231,19 @@ pub fn compile_decorator(compiler: &mut Compiler, node: Node) -> Result<(), Comp
         _ => unreachable!(),
     };
 
+    // #[d] private let m = ... 等价于 private #[d] let m = ...
+    if let Statement::VisibilityStatement(mut visibility_stmt) = decorator.to_decorate {
+        decorator.to_decorate = *visibility_stmt.stmt;
+        visibility_stmt.stmt = Box::new(Statement::ExpressionStatement(ExpressionStatement {
+            expression: Some(Box::new(Expression::Decorator(decorator))),
+        }));
+
+        return compile_visibility_statement(
+            compiler,
+            Node::Statement(Statement::VisibilityStatement(visibility_stmt)),
+        );
+    }
+
     if let &Expression::Identifier(_) = &*decorator.decorator {
         return compile_decorator_ident(compiler, decorator.decorator, decorator.to_decorate);
     } else if let Expression::CallExpression(it) = *decorator.decorator {
@@ -0,0 +1,129 @@
+use crate::{
+    ast::{
+        ast::Node,
+        statements::destructuring_let_statement::DestructureTarget,
+        stmt::Statement,
+    },
+    byte_code_vm::{
+        code::code::{
+            OP_ARRAY_REST, OP_CHECK_ARRAY, OP_CHECK_HASH, OP_CHECK_INSTANCE, OP_CONSTANTS, OP_DUP,
+            OP_GET_FIELD, OP_INDEX, OP_POP, OP_SET_GLOBAL, OP_SET_LOCAL,
+        },
+        compiler::{
+            compiler::{CompileError, Compiler},
+            symbol_table::symbol_table::SymbolScope,
+        },
+    },
+    obj_enum::object::Object,
+    object::{ant_int::AntInt, ant_string::AntString},
+    token::token::Token,
+};
+
+// 将栈顶的值解构到目标中 (消费栈顶)
+fn compile_destructure_target(
+    compiler: &mut Compiler,
+    target: &DestructureTarget,
+    token: &Token,
+) -> Result<(), CompileError> {
+    match target {
+        // _ 表示忽略该值
+        DestructureTarget::Name(name) if name == "_" => {
+            compiler.emit(OP_POP, vec![]);
+        }
+
+        DestructureTarget::Name(name) => {
+            let symbol = compiler.define_symbol(name, Some(token))?;
+
+            compiler.emit(
+                if symbol.scope == SymbolScope::Global {
+                    OP_SET_GLOBAL
+                } else {
+                    OP_SET_LOCAL
+                },
+                vec![symbol.index as u16],
+            );
+        }
+
+        DestructureTarget::Array(items, rest) => {
+            compiler.emit(OP_CHECK_ARRAY, vec![items.len() as u16, rest.is_some() as u16]);
+
+            for (i, item) in items.iter().enumerate() {
+                let index = compiler.add_constant(Object::AntInt(AntInt::from(i)));
+
+                compiler.emit(OP_DUP, vec![1]);
+                compiler.emit(OP_CONSTANTS, vec![index as u16]);
+                compiler.emit(OP_INDEX, vec![]);
+
+                compile_destructure_target(compiler, item, token)?;
+            }
+
+            if let Some(rest) = rest {
+                compiler.emit(OP_DUP, vec![1]);
+                compiler.emit(OP_ARRAY_REST, vec![items.len() as u16]);
+
+                compile_destructure_target(compiler, &DestructureTarget::Name(rest.clone()), token)?;
+            }
+
+            compiler.emit(OP_POP, vec![]);
+        }
+
+        DestructureTarget::Hash(fields) => {
+            let key_indexes = fields
+                .iter()
+                .map(|(key, _)| compiler.add_constant(Object::AntString(AntString::new(key.clone()))))
+                .collect::<Vec<_>>();
+
+            for key_index in &key_indexes {
+                compiler.emit(OP_CONSTANTS, vec![*key_index as u16]);
+            }
+
+            compiler.emit(OP_CHECK_HASH, vec![fields.len() as u16]);
+
+            for ((_, value), key_index) in fields.iter().zip(key_indexes) {
+                compiler.emit(OP_DUP, vec![1]);
+                compiler.emit(OP_CONSTANTS, vec![key_index as u16]);
+                compiler.emit(OP_INDEX, vec![]);
+
+                compile_destructure_target(compiler, value, token)?;
+            }
+
+            compiler.emit(OP_POP, vec![]);
+        }
+
+        DestructureTarget::Object(name, fields) => {
+            let name_index = compiler.add_constant(Object::AntString(AntString::new(name.clone())));
+            compiler.emit(OP_CHECK_INSTANCE, vec![name_index as u16]);
+
+            for (field, value) in fields {
+                let field_index = compiler.add_field(field) as u16;
+
+                compiler.emit(OP_DUP, vec![1]);
+                compiler.emit(OP_GET_FIELD, vec![field_index]);
+
+                compile_destructure_target(compiler, value, token)?;
+            }
+
+            compiler.emit(OP_POP, vec![]);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn compile_destructuring_let(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let let_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!()
+    } {
+        Statement::DestructuringLetStatement(it) => it,
+        _ => unreachable!()
+    };
+
+    if let Err(msg) = compiler.compile_expr(*let_stmt.value) {
+        return Err(CompileError::from_none_token(format!(
+            "error compile let statement: {msg}"
+        )));
+    }
+
+    compile_destructure_target(compiler, &let_stmt.target, &let_stmt.token)
+}
@@ -0,0 +1,156 @@
+use std::rc::Rc;
+
+#[cfg(feature = "debug")]
+use crate::object::id_counter::next_id;
+use crate::{
+    ast::{
+        ast::Node,
+        statements::enum_statement::{EnumStatement, EnumVariantDecl},
+        stmt::Statement,
+    },
+    byte_code_vm::{
+        code::code::{
+            OP_CLASS, OP_CLOSURE, OP_CONSTANTS, OP_ENUM, OP_ENUM_VALUE, OP_GET_LOCAL,
+            OP_RETURN_VALUE,
+        },
+        compiler::compiler::{CompileError, Compiler},
+        scope_info::ScopeInfo,
+    },
+    obj_enum::object::Object,
+    object::{
+        ant_compiled_function::{CompiledFunction, ParamInfo},
+        ant_enum_value::{AntEnumValue, EnumVariant},
+        ant_string::AntString,
+        ant_tuple::AntTuple,
+    },
+};
+
+// 编译带字段变体的构造函数: func (字段...) { OpEnumValue }
+fn compile_variant_constructor(
+    compiler: &mut Compiler,
+    enum_stmt: &EnumStatement,
+    decl: &EnumVariantDecl,
+    template_index: usize,
+) {
+    let scope_name: Rc<str> = format!("{}.{}", enum_stmt.name.value, decl.name).into();
+
+    compiler.enter_scope(ScopeInfo {
+        file_name: enum_stmt.token.file.as_str().into(),
+        scope_name: scope_name.clone(),
+    });
+
+    for (index, field) in decl.fields.iter().enumerate() {
+        compiler.symbol_table.borrow_mut().define(field);
+        compiler.emit(OP_GET_LOCAL, vec![index as u16]);
+    }
+
+    compiler.emit(OP_ENUM_VALUE, vec![template_index as u16]);
+    compiler.emit(OP_RETURN_VALUE, vec![]);
+
+    let local_count = compiler.symbol_table.borrow().num_definitions;
+
+    let instructions = compiler.leave_scope().borrow().clone();
+
+    let params = ParamInfo {
+        names: decl.fields.iter().map(|it| it.as_str().into()).collect(),
+        required: decl.fields.len(),
+        rest: false,
+        keywords: false,
+        type_hints: vec![None; decl.fields.len()].into(),
+    };
+
+    let compiled_function = CompiledFunction {
+        #[cfg(feature = "debug")]
+        id: next_id(),
+        instructions: instructions.into(),
+        local_count,
+        param_count: decl.fields.len(),
+        params: Rc::new(params),
+        return_type: None,
+        unchecked: false,
+        is_generator: false,
+        captures: Rc::default(),
+        scope_info: ScopeInfo {
+            file_name: enum_stmt.token.file.as_str().into(),
+            scope_name,
+        },
+    };
+
+    let constant_index = compiler.add_constant(Object::CompiledFunction(compiled_function)) as u16;
+
+    compiler.emit(OP_CLOSURE, vec![constant_index, 0]);
+}
+
+// 枚举编译为以变体名为成员的只读类: 无字段的变体为枚举值, 有字段的变体为构造函数
+pub fn compile_enum_statement(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let enum_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!(),
+    } {
+        Statement::EnumStatement(it) => it,
+        _ => unreachable!(),
+    };
+
+    let symbol = compiler.define_symbol(&enum_stmt.name.value, Some(&enum_stmt.name.token))?;
+
+    let enum_name: Rc<str> = enum_stmt.name.value.as_str().into();
+
+    let mut templates = vec![];
+
+    for (tag, decl) in enum_stmt.variants.iter().enumerate() {
+        let variant = Rc::new(EnumVariant {
+            enum_name: enum_name.clone(),
+            name: decl.name.as_str().into(),
+            tag,
+            fields: decl.fields.iter().map(|it| it.as_str().into()).collect(),
+        });
+
+        // 变体的模板值 (不含字段值), 由 OpEnumValue 据此构造枚举值
+        let template = Object::AntEnumValue(AntEnumValue::new(variant, vec![]));
+        let template_index = compiler.add_constant(template.clone());
+
+        templates.push(template);
+
+        let name_index = compiler.add_constant(Object::AntString(AntString::new(decl.name.clone())));
+        compiler.emit(OP_CONSTANTS, vec![name_index as u16]);
+
+        if decl.fields.is_empty() {
+            compiler.emit(OP_CONSTANTS, vec![template_index as u16]);
+        } else {
+            compile_variant_constructor(compiler, &enum_stmt, decl, template_index);
+        }
+    }
+
+    let variant_names = enum_stmt
+        .variants
+        .iter()
+        .map(|it| Object::AntString(AntString::new(it.name.clone())))
+        .collect::<Vec<_>>();
+
+    let name_constant_index = compiler.add_constant(Object::AntString(AntString::new(
+        enum_stmt.name.value.clone(),
+    )));
+
+    let readonly_constant_index =
+        compiler.add_constant(Object::AntTuple(AntTuple::from(variant_names)));
+    let private_constant_index = compiler.add_constant(Object::AntTuple(AntTuple::from(vec![])));
+
+    compiler.emit(
+        OP_CLASS,
+        vec![
+            (enum_stmt.variants.len() * 2) as u16,
+            name_constant_index as u16,
+            readonly_constant_index as u16,
+            private_constant_index as u16,
+            0,
+        ],
+    );
+
+    // 记录变体, 供限定的变体模式 (State.Done) 按变体序号匹配
+    let variants_constant_index = compiler.add_constant(Object::AntTuple(AntTuple::from(templates)));
+    compiler.emit(OP_ENUM, vec![variants_constant_index as u16]);
+
+    compiler.store_symbol(&symbol);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::{
+    ast::{ast::Node, stmt::Statement},
+    byte_code_vm::{
+        code::code::{OP_GET_ITER, OP_ITER_NEXT, OP_JUMP, OP_POP, OP_SET_GLOBAL, OP_SET_LOCAL},
+        compiler::{
+            compiler::{CompileError, Compiler},
+            symbol_table::symbol_table::SymbolScope,
+        },
+        constants::FAKE_OFFSET_JUMP,
+    },
+};
+
+pub fn compile_for_statement(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let for_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!()
+    } {
+        Statement::ForStatement(it) => it,
+        _ => unreachable!()
+    };
+
+    if let Err(msg) = compiler.compile_expr(*for_stmt.iterable) {
+        return Err(CompileError::from_none_token(
+            format!("error compile for loop iterable: \n{msg}")
+        ));
+    }
+
+    // 迭代器在整个循环期间留在栈顶
+    compiler.emit(OP_GET_ITER, vec![]);
+
+    let start_ip = compiler.current_instructions().borrow().len();
+
+    let iter_next_command_pos = compiler.emit(OP_ITER_NEXT, vec![FAKE_OFFSET_JUMP]);
+
+    let symbol = compiler.define_symbol(&for_stmt.var.value, Some(&for_stmt.var.token))?;
+
+    compiler.emit(
+        if symbol.scope == SymbolScope::Global {
+            OP_SET_GLOBAL
+        } else {
+            OP_SET_LOCAL
+        },
+        vec![symbol.index as u16],
+    );
+
+    let prev_break_positions = std::mem::take(&mut compiler.break_command_pos);
+    let prev_continue_positions = std::mem::take(&mut compiler.continue_command_pos);
+    let prev_try_depth = std::mem::take(&mut compiler.try_depth_in_loop);
+
+    if let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(for_stmt.block)) {
+        return Err(CompileError::from_none_token(
+            format!("error compile for loop body: \n{msg}")
+        ));
+    }
+
+    compiler.emit(OP_JUMP, vec![start_ip as u16]);
+
+    let for_loop_end = compiler.current_instructions().borrow().len() as u16;
+
+    // 弹出迭代器 (迭代结束与 break 都会跳到这里)
+    compiler.emit(OP_POP, vec![]);
+
+    // 回填 OP_ITER_NEXT 的 操作数
+    compiler.change_operand(iter_next_command_pos, for_loop_end);
+
+    // 回填 break 的操作数 — 仅回填在本次 for 编译期间收集到的位置
+    for pos in compiler.break_command_pos.clone() {
+        compiler.change_operand(pos, for_loop_end);
+    }
+
+    // 回填 continue 的操作数 — 仅回填在本次 for 编译期间收集到的位置
+    for pos in compiler.continue_command_pos.clone() {
+        compiler.change_operand(pos, start_ip as u16);
+    }
+
+    // 恢复外层的 break/continue 位置记录（我们已经回填了本次循环的）
+    compiler.break_command_pos = prev_break_positions;
+    compiler.continue_command_pos = prev_continue_positions;
+    compiler.try_depth_in_loop = prev_try_depth;
+
+    Ok(())
+}
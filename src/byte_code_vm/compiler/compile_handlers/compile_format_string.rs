@@ -0,0 +1,53 @@
+use crate::{
+    ast::{ast::Node, expr::Expression, expressions::format_string_literal::FormatStringPart},
+    byte_code_vm::{
+        code::code::{OP_BUILD_STRING, OP_CONSTANTS, OP_FORMAT_VALUE},
+        compiler::compiler::{CompileError, Compiler},
+    },
+    obj_enum::object::Object,
+    object::ant_string::AntString,
+};
+
+pub fn compile_format_string(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let format_string = match match node {
+        Node::Expression(expr) => expr,
+        _ => unreachable!()
+    } {
+        Expression::FormatStringLiteral(it) => it,
+        _ => unreachable!()
+    };
+
+    let parts_len = format_string.parts.len();
+
+    if parts_len == 0 {
+        let constant_index = compiler.add_constant(Object::AntString(AntString::new(String::new())));
+        compiler.emit(OP_CONSTANTS, vec![constant_index as u16]);
+
+        return Ok(());
+    }
+
+    for part in format_string.parts {
+        match part {
+            FormatStringPart::Literal(s) => {
+                let constant_index = compiler.add_constant(Object::AntString(AntString::new(s)));
+                compiler.emit(OP_CONSTANTS, vec![constant_index as u16]);
+            }
+
+            FormatStringPart::Expression(expr, spec) => {
+                if let Err(msg) = compiler.compile_expr(*expr) {
+                    return Err(CompileError::from_none_token(
+                        format!("error compile format string expression: \n{msg}")
+                    ))
+                }
+
+                // 格式说明符以字符串常量的形式保存, 无说明符时为空字符串
+                let spec_index = compiler.add_constant(Object::AntString(AntString::new(spec.unwrap_or_default())));
+                compiler.emit(OP_FORMAT_VALUE, vec![spec_index as u16]);
+            }
+        }
+    }
+
+    compiler.emit(OP_BUILD_STRING, vec![parts_len as u16]);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::{
+    ast::{ast::Node, stmt::Statement},
+    byte_code_vm::{
+        code::code::{OP_IMPORT_MEMBER, OP_LOAD_MODULE, OP_POP, OP_SET_GLOBAL, OP_SET_LOCAL},
+        compiler::{
+            compiler::{CompileError, Compiler},
+            symbol_table::symbol_table::SymbolScope,
+        },
+    },
+    obj_enum::object::Object,
+    object::ant_string::AntString,
+};
+
+pub fn compile_from_import_statement(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let from_import_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!()
+    } {
+        Statement::FromImportStatement(it) => it,
+        _ => unreachable!()
+    };
+
+    let mod_name_index = compiler.add_constant(Object::AntString(AntString::new(
+        from_import_stmt.module_path(),
+    )));
+
+    // 模块只加载一次, 在导入所有成员期间留在栈顶
+    compiler.emit(OP_LOAD_MODULE, vec![mod_name_index as u16]);
+
+    for (name, alias) in &from_import_stmt.members {
+        let field_index = compiler.add_field(&name.value) as u16;
+
+        compiler.emit(OP_IMPORT_MEMBER, vec![field_index]);
+
+        let bound = alias.as_ref().unwrap_or(name);
+        let symbol = compiler.define_symbol(&bound.value, Some(&bound.token))?;
+
+        compiler.emit(
+            if symbol.scope == SymbolScope::Global {
+                OP_SET_GLOBAL
+            } else {
+                OP_SET_LOCAL
+            },
+            vec![symbol.index as u16],
+        );
+    }
+
+    // 弹出模块
+    compiler.emit(OP_POP, vec![]);
+
+    Ok(())
+}
@@ -1,18 +1,25 @@
+use std::rc::Rc;
+
 #[cfg(feature = "debug")]
 use crate::object::id_counter::next_id;
 use crate::{
     ast::{
-        ast::{INode, Node, TypeNameGetter},
+        ast::{INode, Node},
         expr::Expression,
+        expressions::function_expression::ParamKind,
         stmt::Statement,
     },
     byte_code_vm::{
-        code::code::{OP_CLOSURE, OP_POP, OP_RETURN_VALUE, OP_SET_GLOBAL, OP_SET_LOCAL},
+        code::code::{make, OP_CLOSURE, OP_JUMP_IF_BOUND, OP_POP, OP_RETURN, OP_RETURN_VALUE, OP_SET_GLOBAL, OP_SET_LOCAL},
         compiler::compiler::{CompileError, Compiler},
+        constants::FAKE_OFFSET_JUMP,
         scope_info::ScopeInfo,
     },
     obj_enum::object::Object,
-    object::ant_compiled_function::CompiledFunction,
+    object::{
+        ant_compiled_function::{CompiledFunction, ParamInfo},
+        type_hint::TypeHint,
+    },
 };
 
 pub fn compile_function_expression(
@@ -32,7 +39,7 @@ pub fn compile_function_expression(
     let func_token = func_expr.token();
 
     let symbol_index = if let Some(name) = &func_expr.name {
-        Some(compiler.symbol_table.borrow_mut().define(name).index as u16)
+        Some(compiler.define_symbol(name, Some(&func_token))?.index as u16)
     } else {
         None
     };
@@ -57,22 +64,31 @@ pub fn compile_function_expression(
             .define_function_name(name);
     }
 
-    let mut param_vec = vec![];
-
+    // 形参依次占据局部变量槽位: 普通形参, *rest, **opts
     for param in &func_expr.params {
-        match &**param {
-            Expression::Identifier(it) => param_vec.push(it),
-            _ => {
-                return Err(CompileError::from(
-                    format!("expected an identifier, got: {}", param.type_name()),
-                    Some(param.token()),
-                ));
-            }
-        }
+        compiler.define_symbol(&param.name.value, Some(&param.name.token))?;
     }
 
-    for param in param_vec {
-        compiler.symbol_table.borrow_mut().define(&param.value);
+    // 默认值在调用时求值: 未传入的形参槽位为 Uninit, 此时计算默认值并写入
+    for (index, param) in func_expr.params.iter().enumerate() {
+        if let Some(default) = &param.default {
+            let jump_pos = compiler.emit(OP_JUMP_IF_BOUND, vec![index as u16, FAKE_OFFSET_JUMP]);
+
+            if let Err(msg) = compiler.compile_expr((**default).clone()) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile default value of parameter '{}': \n{msg}",
+                    param.name.value
+                )));
+            }
+
+            compiler.emit(OP_SET_LOCAL, vec![index as u16]);
+
+            let after_default = compiler.current_instructions().borrow().len();
+            compiler.replace_instruction(
+                jump_pos,
+                make(OP_JUMP_IF_BOUND, &vec![index as u16, after_default as u16]),
+            );
+        }
     }
 
     let compile_body_result = compiler.compile_stmt(Statement::BlockStatement(func_expr.block));
@@ -87,16 +103,36 @@ pub fn compile_function_expression(
         compiler.remove_last_pop_to(OP_RETURN_VALUE, &vec![]);
     }
 
+    // 函数体末尾没有值时隐式返回 None (也用于检查返回类型注解)
+    if !compiler.last_instruction_is(OP_RETURN_VALUE) {
+        compiler.emit(OP_RETURN, vec![]);
+    }
+
     let free_symbols = compiler.symbol_table.borrow().free_symbols.clone();
 
     let local_count = compiler.symbol_table.borrow().num_definitions;
     let param_count = func_expr.params.len();
 
-    let instructions = compiler.leave_scope().borrow().clone();
+    let normal_params = func_expr
+        .params
+        .iter()
+        .filter(|it| it.kind == ParamKind::Normal)
+        .collect::<Vec<_>>();
+
+    let params = ParamInfo {
+        names: normal_params.iter().map(|it| it.name.value.as_str().into()).collect(),
+        required: normal_params.iter().filter(|it| it.default.is_none()).count(),
+        rest: func_expr.params.iter().any(|it| it.kind == ParamKind::Rest),
+        keywords: func_expr.params.iter().any(|it| it.kind == ParamKind::Keywords),
+        type_hints: normal_params
+            .iter()
+            .map(|it| it.type_hint.clone().map(TypeHint::new))
+            .collect(),
+    };
 
-    for symbol in &free_symbols {
-        compiler.load_symbol(symbol);
-    }
+    let is_generator = compiler.scopes[compiler.scope_index].is_generator;
+
+    let instructions = compiler.leave_scope().borrow().clone();
 
     let compiled_function = CompiledFunction {
         #[cfg(feature = "debug")]
@@ -104,6 +140,11 @@ pub fn compile_function_expression(
         instructions: instructions.into(),
         local_count,
         param_count,
+        params: Rc::new(params),
+        return_type: func_expr.return_type.clone().map(|it| Rc::new(TypeHint::new(it))),
+        unchecked: false,
+        is_generator,
+        captures: Compiler::capture_symbols(&free_symbols),
         scope_info: ScopeInfo {
             file_name: func_expr.token.file.as_str().into(),
             scope_name: if let Some(name) = &func_expr.name {
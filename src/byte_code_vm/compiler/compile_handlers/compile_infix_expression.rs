@@ -1,8 +1,9 @@
 use crate::{
     ast::{ast::Node, expr::Expression},
     byte_code_vm::{
-        code::code::{INFIX_OPERATOR_TO_OPCODE, OP_GT},
+        code::code::{INFIX_OPERATOR_TO_OPCODE, OP_JUMP_IF_FALSE_OR_POP, OP_JUMP_IF_TRUE_OR_POP},
         compiler::compiler::{CompileError, Compiler},
+        constants::FAKE_OFFSET_JUMP,
     },
 };
 
@@ -18,30 +19,36 @@ pub fn compile_infix_expression(
         _ => unreachable!()
     };
 
-    if infix_expr.operator.value == "<" {
+    if let Err(left_err) = compiler.compile_expr(*infix_expr.left) {
+        return Err(CompileError::from_none_token(
+            format!("error compiling left expression: \n{}", left_err)
+        ));
+    };
+
+    // and / or 短路求值, 结果为决定结果的那个操作数
+    let operator = infix_expr.operator.value.to_lowercase();
+    if operator == "and" || operator == "or" {
+        let jump_pos = compiler.emit(
+            if operator == "and" {
+                OP_JUMP_IF_FALSE_OR_POP
+            } else {
+                OP_JUMP_IF_TRUE_OR_POP
+            },
+            vec![FAKE_OFFSET_JUMP],
+        );
+
         if let Err(right_err) = compiler.compile_expr(*infix_expr.right) {
             return Err(CompileError::from_none_token(
                 format!("error compiling right expression: \n{}", right_err)
             ));
         };
 
-        if let Err(left_err) = compiler.compile_expr(*infix_expr.left) {
-            return Err(CompileError::from_none_token(
-                format!("error compiling left expression: \n{}", left_err)
-            ));
-        };
-
-        compiler.emit(OP_GT, vec![]);
+        let end = compiler.current_instructions().borrow().len() as u16;
+        compiler.change_operand(jump_pos, end);
 
         return Ok(());
     }
 
-    if let Err(left_err) = compiler.compile_expr(*infix_expr.left) {
-        return Err(CompileError::from_none_token(
-            format!("error compiling left expression: \n{}", left_err)
-        ));
-    };
-
     if let Err(right_err) = compiler.compile_expr(*infix_expr.right) {
         return Err(CompileError::from_none_token(
             format!("error compiling right expression: \n{}", right_err)
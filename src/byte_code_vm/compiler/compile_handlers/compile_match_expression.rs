@@ -0,0 +1,340 @@
+use crate::{
+    ast::{
+        ast::Node,
+        expr::Expression,
+        expressions::match_expression::{MatchArm, Pattern},
+        stmt::Statement,
+    },
+    byte_code_vm::{
+        code::code::{
+            OP_CONSTANTS, OP_DUP, OP_GET_FIELD, OP_INDEX, OP_JUMP, OP_JUMP_NOT_TRUTHY, OP_MATCH_ARRAY,
+            OP_MATCH_CLASS, OP_MATCH_EQ, OP_MATCH_FAIL, OP_MATCH_HASH, OP_MATCH_HAS_KEY,
+            OP_MATCH_VARIANT, OP_NONE, OP_POP, OP_SET_GLOBAL, OP_SET_LOCAL,
+        },
+        compiler::{
+            compiler::{CompileError, Compiler},
+            symbol_table::symbol_table::{Symbol, SymbolScope},
+        },
+        constants::FAKE_OFFSET_JUMP,
+    },
+    obj_enum::object::Object,
+    object::{ant_int::AntInt, ant_string::AntString},
+};
+
+// 从被匹配的值到子模式所对应的值的访问路径
+enum PathStep<'a> {
+    Index(usize),           // 数组下标
+    Key(&'a Expression),    // 字典键
+    Field(&'a str),         // 对象字段
+}
+
+// 复制栈顶的被匹配值, 并沿路径取出子值
+fn load_path(compiler: &mut Compiler, path: &[PathStep]) -> Result<(), CompileError> {
+    compiler.emit(OP_DUP, vec![1]);
+
+    for step in path {
+        match step {
+            PathStep::Index(i) => {
+                let constant_index = compiler.add_constant(Object::AntInt(AntInt::from(*i)));
+                compiler.emit(OP_CONSTANTS, vec![constant_index as u16]);
+                compiler.emit(OP_INDEX, vec![]);
+            }
+
+            PathStep::Key(key) => {
+                compiler.compile_expr((*key).clone())?;
+                compiler.emit(OP_INDEX, vec![]);
+            }
+
+            PathStep::Field(field) => {
+                let field_index = compiler.add_field(field) as u16;
+                compiler.emit(OP_GET_FIELD, vec![field_index]);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// 栈顶为匹配结果, 不匹配时跳转到下一个分支 (稍后回填)
+fn emit_fail_jump(compiler: &mut Compiler, fail_jumps: &mut Vec<usize>) {
+    fail_jumps.push(compiler.emit(OP_JUMP_NOT_TRUTHY, vec![FAKE_OFFSET_JUMP]));
+}
+
+fn compile_pattern_test<'a>(
+    compiler: &mut Compiler,
+    pattern: &'a Pattern,
+    path: &mut Vec<PathStep<'a>>,
+    fail_jumps: &mut Vec<usize>,
+) -> Result<(), CompileError> {
+    match pattern {
+        Pattern::Wildcard | Pattern::Binding(_) => {}
+
+        Pattern::Literal(literal) => {
+            load_path(compiler, path)?;
+
+            if let Err(msg) = compiler.compile_expr((**literal).clone()) {
+                return Err(CompileError::from_none_token(format!(
+                    "error compile literal pattern: \n{msg}"
+                )));
+            }
+
+            compiler.emit(OP_MATCH_EQ, vec![]);
+            emit_fail_jump(compiler, fail_jumps);
+        }
+
+        Pattern::Array(items) => {
+            load_path(compiler, path)?;
+            compiler.emit(OP_MATCH_ARRAY, vec![items.len() as u16]);
+            emit_fail_jump(compiler, fail_jumps);
+
+            for (i, item) in items.iter().enumerate() {
+                path.push(PathStep::Index(i));
+                compile_pattern_test(compiler, item, path, fail_jumps)?;
+                path.pop();
+            }
+        }
+
+        Pattern::Hash(pairs) => {
+            load_path(compiler, path)?;
+            compiler.emit(OP_MATCH_HASH, vec![]);
+            emit_fail_jump(compiler, fail_jumps);
+
+            for (key, value) in pairs {
+                load_path(compiler, path)?;
+                compiler.compile_expr((**key).clone())?;
+                compiler.emit(OP_MATCH_HAS_KEY, vec![]);
+                emit_fail_jump(compiler, fail_jumps);
+
+                path.push(PathStep::Key(key));
+                compile_pattern_test(compiler, value, path, fail_jumps)?;
+                path.pop();
+            }
+        }
+
+        Pattern::Class(name, fields) => {
+            load_path(compiler, path)?;
+
+            let name_index = compiler.add_constant(Object::AntString(AntString::new(name.clone())));
+            compiler.emit(OP_MATCH_CLASS, vec![name_index as u16]);
+            emit_fail_jump(compiler, fail_jumps);
+
+            compile_field_tests(compiler, fields, path, fail_jumps)?;
+        }
+
+        Pattern::Variant(enum_name, name, fields) => {
+            load_path(compiler, path)?;
+
+            let enum_symbol = match compiler.symbol_table.borrow_mut().resolve(enum_name) {
+                Some(it) => it,
+                None => {
+                    return Err(CompileError::from_none_token(format!(
+                        "undefined enum in pattern: {enum_name}."
+                    )));
+                }
+            };
+
+            compiler.load_symbol(&enum_symbol);
+
+            let name_index = compiler.add_constant(Object::AntString(AntString::new(name.clone())));
+            compiler.emit(OP_MATCH_VARIANT, vec![name_index as u16]);
+            emit_fail_jump(compiler, fail_jumps);
+
+            compile_field_tests(compiler, fields, path, fail_jumps)?;
+        }
+    }
+
+    Ok(())
+}
+
+// 类 / 变体模式的字段: 字段必须存在且其值匹配子模式
+fn compile_field_tests<'a>(
+    compiler: &mut Compiler,
+    fields: &'a [(String, Pattern)],
+    path: &mut Vec<PathStep<'a>>,
+    fail_jumps: &mut Vec<usize>,
+) -> Result<(), CompileError> {
+    for (field, value) in fields {
+        load_path(compiler, path)?;
+
+        let field_index = compiler.add_constant(Object::AntString(AntString::new(field.clone())));
+        compiler.emit(OP_CONSTANTS, vec![field_index as u16]);
+        compiler.emit(OP_MATCH_HAS_KEY, vec![]);
+        emit_fail_jump(compiler, fail_jumps);
+
+        path.push(PathStep::Field(field));
+        compile_pattern_test(compiler, value, path, fail_jumps)?;
+        path.pop();
+    }
+
+    Ok(())
+}
+
+// 模式绑定只在所在分支内可见, shadowed 记录被遮蔽的外部符号以便分支结束后恢复
+fn compile_pattern_bindings<'a>(
+    compiler: &mut Compiler,
+    pattern: &'a Pattern,
+    path: &mut Vec<PathStep<'a>>,
+    shadowed: &mut Vec<(String, Option<Symbol>)>,
+) -> Result<(), CompileError> {
+    match pattern {
+        Pattern::Wildcard | Pattern::Literal(_) => {}
+
+        Pattern::Binding(name) => {
+            load_path(compiler, path)?;
+
+            let outer = compiler.symbol_table.borrow().store.get(name).cloned();
+            // 分支内的绑定可以遮蔽只读变量, 分支结束后恢复
+            let symbol = compiler.symbol_table.borrow_mut().define(name);
+
+            shadowed.push((name.clone(), outer));
+
+            compiler.emit(
+                if symbol.scope == SymbolScope::Global {
+                    OP_SET_GLOBAL
+                } else {
+                    OP_SET_LOCAL
+                },
+                vec![symbol.index as u16],
+            );
+        }
+
+        Pattern::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                path.push(PathStep::Index(i));
+                compile_pattern_bindings(compiler, item, path, shadowed)?;
+                path.pop();
+            }
+        }
+
+        Pattern::Hash(pairs) => {
+            for (key, value) in pairs {
+                path.push(PathStep::Key(key));
+                compile_pattern_bindings(compiler, value, path, shadowed)?;
+                path.pop();
+            }
+        }
+
+        Pattern::Class(_, fields) | Pattern::Variant(_, _, fields) => {
+            for (field, value) in fields {
+                path.push(PathStep::Field(field));
+                compile_pattern_bindings(compiler, value, path, shadowed)?;
+                path.pop();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// 分支体最后一条语句是否会留下值
+fn body_has_value(body: &Statement) -> bool {
+    let last = match body {
+        Statement::BlockStatement(block) => block.statements.last(),
+        it => Some(it),
+    };
+
+    match last {
+        Some(Statement::ExpressionStatement(expr_stmt)) => match expr_stmt.expression.as_deref() {
+            Some(Expression::FunctionExpression(f)) => f.name.is_none(),
+            Some(Expression::Decorator(_) | Expression::AssignmentExpression(_)) | None => false,
+            Some(_) => true,
+        },
+        _ => false,
+    }
+}
+
+fn compile_match_arm(
+    compiler: &mut Compiler,
+    arm: MatchArm,
+    end_jumps: &mut Vec<usize>,
+) -> Result<(), CompileError> {
+    let mut fail_jumps = vec![];
+
+    compile_pattern_test(compiler, &arm.pattern, &mut vec![], &mut fail_jumps)?;
+
+    let mut shadowed = vec![];
+    compile_pattern_bindings(compiler, &arm.pattern, &mut vec![], &mut shadowed)?;
+
+    if let Some(guard) = arm.guard {
+        if let Err(msg) = compiler.compile_expr(*guard) {
+            return Err(CompileError::from_none_token(format!(
+                "error compile match guard: \n{msg}"
+            )));
+        }
+
+        emit_fail_jump(compiler, &mut fail_jumps);
+    }
+
+    // 匹配成功后先丢弃被匹配值, 避免分支体中的 break / continue / return 遗留栈槽
+    compiler.emit(OP_POP, vec![]);
+
+    let has_value = body_has_value(&arm.body);
+
+    if let Err(msg) = compiler.compile_stmt(arm.body) {
+        return Err(CompileError::from_none_token(format!(
+            "error compile match arm: \n{msg}"
+        )));
+    }
+
+    if !has_value {
+        compiler.emit(OP_NONE, vec![]);
+    } else if compiler.last_instruction_is(OP_POP) {
+        compiler.remove_last_instruction();
+    }
+
+    // 恢复被模式绑定遮蔽的符号
+    {
+        let mut symbol_table = compiler.symbol_table.borrow_mut();
+
+        for (name, outer) in shadowed.into_iter().rev() {
+            match outer {
+                Some(symbol) => symbol_table.store.insert(name, symbol),
+                None => symbol_table.store.remove(&name),
+            };
+        }
+    }
+
+    end_jumps.push(compiler.emit(OP_JUMP, vec![FAKE_OFFSET_JUMP]));
+
+    // 回填不匹配时的跳转, 指向下一个分支
+    let next_arm_pos = compiler.current_instructions().borrow().len();
+
+    for pos in fail_jumps {
+        compiler.change_operand(pos, next_arm_pos as u16);
+    }
+
+    Ok(())
+}
+
+pub fn compile_match_expression(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let match_expr = match match node {
+        Node::Expression(expr) => expr,
+        _ => unreachable!()
+    } {
+        Expression::MatchExpression(it) => it,
+        _ => unreachable!()
+    };
+
+    if let Err(msg) = compiler.compile_expr(*match_expr.value) {
+        return Err(CompileError::from_none_token(format!(
+            "error compile match value: \n{msg}"
+        )));
+    }
+
+    let mut end_jumps = vec![];
+
+    for arm in match_expr.arms {
+        compile_match_arm(compiler, arm, &mut end_jumps)?;
+    }
+
+    // 所有分支均不匹配
+    compiler.emit(OP_MATCH_FAIL, vec![]);
+
+    let end_pos = compiler.current_instructions().borrow().len();
+
+    for pos in end_jumps {
+        compiler.change_operand(pos, end_pos as u16);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::{
+    ast::{ast::{INode, Node}, expr::Expression},
+    byte_code_vm::{
+        code::code::OP_GET_SUPER,
+        compiler::compiler::{CompileError, Compiler},
+    },
+};
+
+// super.member: 在父类链上查找成员, 方法绑定到当前的 self
+pub fn compile_super_member(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let obj_member_expr = match match node {
+        Node::Expression(expr) => expr,
+        _ => unreachable!()
+    } {
+        Expression::ObjectMemberExpression(it) => it,
+        _ => unreachable!()
+    };
+
+    let token = obj_member_expr.left.token();
+
+    let base = match compiler.class_bases.last().cloned().flatten() {
+        Some(it) => it,
+        None => {
+            return Err(CompileError::from(
+                String::from("cannot use 'super' in a class without parent class"),
+                Some(token),
+            ));
+        }
+    };
+
+    let field = if let Expression::Identifier(it) = *obj_member_expr.right {
+        it
+    } else {
+        return Err(CompileError::from(
+            String::from("expected an identifier of object member"),
+            Some(obj_member_expr.right.token()),
+        ));
+    };
+
+    let base_symbol = match compiler.symbol_table.borrow_mut().resolve(&base) {
+        Some(it) => it,
+        None => {
+            return Err(CompileError::from(
+                format!("undefined parent class: {base}."),
+                Some(token),
+            ));
+        }
+    };
+
+    let self_symbol = match compiler.symbol_table.borrow_mut().resolve("self") {
+        Some(it) => it,
+        None => {
+            return Err(CompileError::from(
+                String::from("cannot use 'super' outside of a method (missing 'self')"),
+                Some(token),
+            ));
+        }
+    };
+
+    compiler.load_symbol(&base_symbol);
+    compiler.load_symbol(&self_symbol);
+
+    let field_index = compiler.add_field(&field.value) as u16;
+
+    compiler.emit(OP_GET_SUPER, vec![field_index]);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::{
+    ast::{ast::Node, statements::block_statement::BlockStatement, stmt::Statement},
+    byte_code_vm::{
+        code::code::{
+            OP_JUMP, OP_POP, OP_POP_TRY, OP_SET_GLOBAL, OP_SET_LOCAL, OP_SETUP_TRY, OP_THROW,
+        },
+        compiler::{
+            compiler::{CompileError, Compiler},
+            symbol_table::symbol_table::SymbolScope,
+        },
+        constants::FAKE_OFFSET_JUMP,
+    },
+};
+
+/*
+try { A } catch e { B } finally { C } 编译为:
+
+    OpSetupTry catch
+    A
+    OpPopTry
+    OpJump finally
+catch:                  (错误对象在栈顶)
+    OpSetGlobal/OpSetLocal e
+    OpSetupTry rethrow  (仅当有 finally)
+    B
+    OpPopTry
+    OpJump finally
+rethrow:
+    C
+    OpThrow
+finally:
+    C
+
+break / continue / return 离开 try 或 catch 块时, 先 OpPopTry 再执行一份内联的 C
+*/
+pub fn compile_try_statement(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let try_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!()
+    } {
+        Statement::TryStatement(it) => it,
+        _ => unreachable!()
+    };
+
+    let setup_try_command_pos = compiler.emit(OP_SETUP_TRY, vec![FAKE_OFFSET_JUMP]);
+
+    compiler.try_depth_in_loop += 1;
+    compiler.scopes[compiler.scope_index]
+        .try_finally_blocks
+        .push(try_stmt.finally_block.clone());
+
+    if let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(try_stmt.block)) {
+        return Err(CompileError::from_none_token(
+            format!("error compile try body: \n{msg}")
+        ));
+    }
+
+    compiler.try_depth_in_loop -= 1;
+    compiler.scopes[compiler.scope_index].try_finally_blocks.pop();
+
+    compiler.emit(OP_POP_TRY, vec![]);
+
+    let mut jump_to_finally_positions = vec![compiler.emit(OP_JUMP, vec![FAKE_OFFSET_JUMP])];
+
+    let catch_start = compiler.current_instructions().borrow().len() as u16;
+    compiler.change_operand(setup_try_command_pos, catch_start);
+
+    match try_stmt.catch_block {
+        Some(catch_block) => {
+            match &try_stmt.catch_var {
+                Some(var) => {
+                    let symbol = compiler.define_symbol(&var.value, Some(&var.token))?;
+
+                    compiler.emit(
+                        if symbol.scope == SymbolScope::Global {
+                            OP_SET_GLOBAL
+                        } else {
+                            OP_SET_LOCAL
+                        },
+                        vec![symbol.index as u16],
+                    );
+                }
+
+                None => {
+                    compiler.emit(OP_POP, vec![]);
+                }
+            }
+
+            // 有 finally 时, catch 块中抛出的错误也需要先执行 finally 再重新抛出
+            let rethrow_setup_pos = if try_stmt.finally_block.is_some() {
+                compiler.try_depth_in_loop += 1;
+                compiler.scopes[compiler.scope_index]
+                    .try_finally_blocks
+                    .push(try_stmt.finally_block.clone());
+
+                Some(compiler.emit(OP_SETUP_TRY, vec![FAKE_OFFSET_JUMP]))
+            } else {
+                None
+            };
+
+            if let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(catch_block)) {
+                return Err(CompileError::from_none_token(
+                    format!("error compile catch body: \n{msg}")
+                ));
+            }
+
+            if let Some(pos) = rethrow_setup_pos {
+                compiler.try_depth_in_loop -= 1;
+                compiler.scopes[compiler.scope_index].try_finally_blocks.pop();
+
+                compiler.emit(OP_POP_TRY, vec![]);
+                jump_to_finally_positions.push(compiler.emit(OP_JUMP, vec![FAKE_OFFSET_JUMP]));
+
+                let rethrow_start = compiler.current_instructions().borrow().len() as u16;
+                compiler.change_operand(pos, rethrow_start);
+
+                compile_finally_and_rethrow(compiler, &try_stmt.finally_block)?;
+            }
+        }
+
+        None => compile_finally_and_rethrow(compiler, &try_stmt.finally_block)?,
+    }
+
+    let finally_start = compiler.current_instructions().borrow().len() as u16;
+
+    // 回填跳转到 finally 的操作数
+    for pos in jump_to_finally_positions {
+        compiler.change_operand(pos, finally_start);
+    }
+
+    if let Some(finally_block) = try_stmt.finally_block
+        && let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(finally_block))
+    {
+        return Err(CompileError::from_none_token(
+            format!("error compile finally body: \n{msg}")
+        ));
+    }
+
+    Ok(())
+}
+
+// 错误对象在栈顶, 执行 finally 后重新抛出
+fn compile_finally_and_rethrow(
+    compiler: &mut Compiler,
+    finally_block: &Option<BlockStatement>,
+) -> Result<(), CompileError> {
+    if let Some(finally_block) = finally_block
+        && let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(finally_block.clone()))
+    {
+        return Err(CompileError::from_none_token(
+            format!("error compile finally body: \n{msg}")
+        ));
+    }
+
+    compiler.emit(OP_THROW, vec![]);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{
+    ast::{ast::Node, stmt::Statement},
+    byte_code_vm::compiler::{
+        compiler::{CompileError, Compiler},
+        symbol_table::symbol_table::SymbolScope,
+    },
+};
+
+// public / private 只能用于类中, private 将被修饰语句新定义的符号标记为私有
+pub fn compile_visibility_statement(compiler: &mut Compiler, node: Node) -> Result<(), CompileError> {
+    let visibility_stmt = match match node {
+        Node::Statement(stmt) => stmt,
+        _ => unreachable!()
+    } {
+        Statement::VisibilityStatement(it) => it,
+        _ => unreachable!()
+    };
+
+    if compiler.class_names.is_empty() {
+        return Err(CompileError::from(
+            format!("'{}' can only be used in a class body", visibility_stmt.token.value),
+            Some(visibility_stmt.token),
+        ));
+    }
+
+    let before = compiler.symbol_table.borrow().store.clone();
+
+    compiler.compile_stmt(*visibility_stmt.stmt)?;
+
+    if !visibility_stmt.private {
+        return Ok(());
+    }
+
+    for symbol in compiler.symbol_table.borrow_mut().store.values_mut() {
+        if symbol.scope == SymbolScope::Local && before.get(&symbol.name) != Some(symbol) {
+            symbol.private = true;
+        }
+    }
+
+    Ok(())
+}
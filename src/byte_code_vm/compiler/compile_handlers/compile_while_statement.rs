@@ -27,6 +27,7 @@ pub fn compile_while_statement(compiler: &mut Compiler, node: Node) -> Result<()
 
     let prev_break_positions = std::mem::take(&mut compiler.break_command_pos);
     let prev_continue_positions = std::mem::take(&mut compiler.continue_command_pos);
+    let prev_try_depth = std::mem::take(&mut compiler.try_depth_in_loop);
 
     if let Err(msg) = compiler.compile_stmt(Statement::BlockStatement(while_stmt.block)) {
         return Err(CompileError::from_none_token(
@@ -54,6 +55,7 @@ pub fn compile_while_statement(compiler: &mut Compiler, node: Node) -> Result<()
     // 恢复外层的 break/continue 位置记录（我们已经回填了本次循环的）
     compiler.break_command_pos = prev_break_positions;
     compiler.continue_command_pos = prev_continue_positions;
+    compiler.try_depth_in_loop = prev_try_depth;
 
     Ok(())
 }
@@ -6,4 +6,14 @@ pub mod compile_infix_expression;
 pub mod compile_prefix_expression;
 pub mod compile_while_statement;
 pub mod compile_hash_literal;
-pub mod compile_class;
\ No newline at end of file
+pub mod compile_class;
+pub mod compile_for_statement;
+pub mod compile_try_statement;
+pub mod compile_from_import_statement;
+pub mod compile_super_member;
+pub mod compile_compound_assignment;
+pub mod compile_format_string;
+pub mod compile_match_expression;
+pub mod compile_destructuring_let;
+pub mod compile_visibility_statement;
+pub mod compile_enum_statement;
\ No newline at end of file
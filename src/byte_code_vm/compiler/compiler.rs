@@ -4,38 +4,51 @@ use crate::{
     ast::{
         ast::{INode, Node, Program},
         expr::Expression,
+        statements::block_statement::BlockStatement,
         stmt::Statement,
     },
     big_dec,
     builtin::builtin_map::BUILTIN_MAP_INDEX,
     byte_code_vm::{
         code::code::{
-            Instructions, OP_ARRAY, OP_CONSTANTS, OP_CURRENT_CLOSURE, OP_FALSE, OP_GET_BUILTIN,
-            OP_GET_FIELD, OP_GET_FREE, OP_GET_GLOBAL, OP_GET_LOCAL, OP_INDEX, OP_JUMP,
-            OP_LOAD_MODULE, OP_NONE, OP_POP, OP_RETURN_VALUE, OP_SET_FIELD, OP_SET_GLOBAL,
-            OP_SET_INDEX, OP_SET_LOCAL, OP_TEST_PRINT, OP_TRUE, OpCode, make,
+            Instructions, OP_ARRAY, OP_TUPLE, OP_CONSTANTS, OP_CURRENT_CLOSURE, OP_FALSE, OP_GET_BUILTIN,
+            OP_GET_CLASS_MEMBER, OP_GET_FIELD, OP_GET_FREE, OP_GET_GLOBAL, OP_GET_LOCAL, OP_INDEX, OP_JUMP,
+            OP_LOAD_MODULE, OP_NONE, OP_POP, OP_POP_TRY, OP_RETURN_VALUE, OP_SET_FIELD, OP_SET_GLOBAL,
+            OP_SET_FREE, OP_SET_INDEX, OP_GET_SELF_FIELD, OP_SET_SELF_FIELD, OP_SET_LOCAL, OP_YIELD, OP_TEST_PRINT, OP_TRUE, OpCode, make,
         },
         compiler::{
             compile_handlers::{
-                compile_call_expression::compile_call_expression, compile_class::compile_class,
+                compile_call_expression::compile_call_expression, compile_class::compile_class, compile_enum_statement::compile_enum_statement,
+                compile_compound_assignment::compile_compound_assignment,
                 compile_decorator::compile_decorator,
+                compile_destructuring_let::compile_destructuring_let,
+                compile_format_string::compile_format_string,
+                compile_for_statement::compile_for_statement,
+                compile_from_import_statement::compile_from_import_statement,
                 compile_function_expression::compile_function_expression,
                 compile_hash_literal::compile_hash_literal,
                 compile_if_expression::compile_if_expression,
                 compile_infix_expression::compile_infix_expression,
+                compile_match_expression::compile_match_expression,
                 compile_prefix_expression::compile_prefix_expression,
+                compile_super_member::compile_super_member,
+                compile_try_statement::compile_try_statement,
+                compile_visibility_statement::compile_visibility_statement,
                 compile_while_statement::compile_while_statement,
             },
             constant_pool::{CONSTANT_POOL_0_256, I64_CONSTANT_POOL_0_256},
             symbol_table::symbol_table::{Symbol, SymbolScope, SymbolTable},
         },
-        constants::FAKE_OFFSET_JUMP,
+        constants::{FAKE_OFFSET_JUMP, UNINIT_OBJECT},
         scope_info::ScopeInfo,
     },
     obj_enum::object::Object,
-    object::{ant_double::AntDouble, ant_i64::AntI64, ant_int::AntInt, ant_string::AntString},
+    object::{
+        ant_compiled_function::Capture, ant_double::AntDouble, ant_i64::AntI64, ant_int::AntInt,
+        ant_string::AntString,
+    },
     rc_ref_cell,
-    token::token::Token,
+    token::{token::Token, token_type::TokenType},
 };
 
 #[derive(Debug, Clone)]
@@ -44,6 +57,10 @@ pub struct CompilationScope {
     pub instructions: Rc<RefCell<Instructions>>,
     pub last_instruction: EmittedInstruction,
     pub previous_instruction: EmittedInstruction,
+    pub is_generator: bool, // 作用域中出现过 yield
+
+    // 当前所在的 try 块 (由外到内) 各自的 finally 块, break/continue/return 离开时需要执行
+    pub try_finally_blocks: Vec<Option<BlockStatement>>,
 }
 
 #[derive(Debug, Clone)]
@@ -86,6 +103,8 @@ impl CompilationScope {
             instructions,
             last_instruction,
             previous_instruction,
+            is_generator: false,
+            try_finally_blocks: vec![],
         }
     }
 }
@@ -170,6 +189,18 @@ pub struct Compiler {
     pub break_command_pos: Vec<usize>,
     pub continue_command_pos: Vec<usize>,
 
+    // 当前循环内嵌套的 try 块数量, break/continue 跳出前需要弹出对应的异常处理器
+    pub try_depth_in_loop: usize,
+
+    // 正在编译的类的父类名 (由内到外), 用于 super
+    pub class_bases: Vec<Option<String>>,
+
+    // 正在编译的类名 (由内到外), 用于在方法中通过 self 访问私有成员
+    pub class_names: Vec<String>,
+
+    // 正在编译的接口体所在的作用域, 只有其直接成员可以是未定义的 (let x;)
+    pub interface_scope: Option<usize>,
+
     pub symbol_table: Rc<RefCell<SymbolTable>>,
 
     pub scopes: Vec<CompilationScope>,
@@ -202,6 +233,10 @@ impl Compiler {
             field_pool: rc_ref_cell!(vec![]),
             break_command_pos: vec![],
             continue_command_pos: vec![],
+            try_depth_in_loop: 0,
+            class_bases: vec![],
+            class_names: vec![],
+            interface_scope: None,
             symbol_table,
             scope_index: 0,
             scopes: vec![main_scope],
@@ -225,6 +260,10 @@ impl Compiler {
             field_pool,
             break_command_pos: vec![],
             continue_command_pos: vec![],
+            try_depth_in_loop: 0,
+            class_bases: vec![],
+            class_names: vec![],
+            interface_scope: None,
             symbol_table,
             scope_index: 0,
             scopes: vec![main_scope],
@@ -235,10 +274,23 @@ impl Compiler {
     pub fn compile_expr(&mut self, node: Expression) -> Result<(), CompileError> {
         match node {
             Expression::TupleExpression(mut tuple_expr) => {
+                // 单个元素的括号仅用于分组
                 if tuple_expr.expressions.len() == 1 {
                     return self.compile_expr(*tuple_expr.expressions.remove(0));
                 }
 
+                let tuple_len = tuple_expr.expressions.len();
+
+                for expr in tuple_expr.expressions {
+                    if let Err(msg) = self.compile_expr(*expr) {
+                        return Err(CompileError::from_none_token(format!(
+                            "error compile tuple item: \n{msg}"
+                        )));
+                    }
+                }
+
+                self.emit(OP_TUPLE, vec![tuple_len as u16]);
+
                 Ok(())
             }
 
@@ -311,7 +363,35 @@ impl Compiler {
                 Ok(())
             }
 
+            // 未定义的成员只能作为接口的直接成员声明
+            Expression::UninitLiteral(literal) => {
+                if self.interface_scope != Some(self.scope_index) {
+                    let msg = if self.class_names.is_empty() {
+                        format!("missing value of variable '{}'", literal.token.value)
+                    } else {
+                        format!(
+                            "member '{}' without value can only be declared in an interface",
+                            literal.token.value
+                        )
+                    };
+
+                    return Err(CompileError::from(msg, Some(literal.token)));
+                }
+
+                let index = self.add_constant(UNINIT_OBJECT.clone());
+                self.emit(OP_CONSTANTS, vec![index as u16]);
+
+                Ok(())
+            }
+
             Expression::AssignmentExpression(assign_expr) => {
+                if assign_expr.token.token_type != TokenType::Assign {
+                    return compile_compound_assignment(
+                        self,
+                        Node::Expression(Expression::AssignmentExpression(assign_expr)),
+                    );
+                }
+
                 let result = self.compile_expr(*assign_expr.value);
 
                 if let Err(msg) = result {
@@ -333,14 +413,14 @@ impl Compiler {
                             ));
                         };
 
-                        self.emit(
-                            if symbol.scope == SymbolScope::Global {
-                                OP_SET_GLOBAL
-                            } else {
-                                OP_SET_LOCAL
-                            },
-                            vec![symbol.index as u16],
-                        );
+                        if symbol.readonly {
+                            return Err(CompileError::from(
+                                format!("cannot assign to readonly variable: {}.", ident.value),
+                                Some(ident.token),
+                            ));
+                        }
+
+                        self.store_symbol(&symbol);
                     }
 
                     Expression::IndexExpression(index_expr) => {
@@ -362,6 +442,7 @@ impl Compiler {
                     Expression::ObjectMemberExpression(obj_member) => {
                         if let Expression::Identifier(field) = *obj_member.right {
                             let field_index = self.add_field(&field.value) as u16;
+                            let self_class = self.self_access_class(&obj_member.left);
 
                             if let Err(msg) = self.compile_expr(*obj_member.left) {
                                 return Err(CompileError::from_none_token(format!(
@@ -369,8 +450,29 @@ impl Compiler {
                                 )));
                             }
 
-                            self.emit(OP_SET_FIELD, vec![field_index]);
+                            self.emit_set_field(field_index, self_class);
+                        }
+                    }
+
+                    Expression::ClassMemberExpression(class_member) => {
+                        let field = if let Expression::Identifier(it) = *class_member.right {
+                            it
+                        } else {
+                            return Err(CompileError::from(
+                                String::from("expected an identifier of class member"),
+                                Some(class_member.right.token()),
+                            ));
+                        };
+
+                        let field_index = self.add_field(&field.value) as u16;
+
+                        if let Err(msg) = self.compile_expr(*class_member.left) {
+                            return Err(CompileError::from_none_token(format!(
+                                "error compile class: \n{msg}"
+                            )));
                         }
+
+                        self.emit(OP_SET_FIELD, vec![field_index]);
                     }
 
                     _ => {
@@ -437,6 +539,18 @@ impl Compiler {
             }
 
             Expression::ObjectMemberExpression(obj_member_expr) => {
+                if !self.class_bases.is_empty()
+                    && let Expression::Identifier(ident) = &*obj_member_expr.left
+                    && ident.value == "super"
+                {
+                    return compile_super_member(
+                        self,
+                        Node::Expression(Expression::ObjectMemberExpression(obj_member_expr)),
+                    );
+                }
+
+                let self_class = self.self_access_class(&obj_member_expr.left);
+
                 if let Err(msg) = self.compile_expr(*obj_member_expr.left) {
                     return Err(CompileError::from_none_token(format!(
                         "error compile object: {msg}"
@@ -454,7 +568,30 @@ impl Compiler {
 
                 let field_index = self.add_field(&field.value) as u16;
 
-                self.emit(OP_GET_FIELD, vec![field_index]);
+                self.emit_get_field(field_index, self_class);
+
+                Ok(())
+            }
+
+            Expression::ClassMemberExpression(class_member_expr) => {
+                if let Err(msg) = self.compile_expr(*class_member_expr.left) {
+                    return Err(CompileError::from_none_token(format!(
+                        "error compile class: {msg}"
+                    )));
+                }
+
+                let field = if let Expression::Identifier(it) = *class_member_expr.right {
+                    it
+                } else {
+                    return Err(CompileError::from(
+                        String::from("expected an identifier of class member"),
+                        Some(class_member_expr.right.token()),
+                    ));
+                };
+
+                let field_index = self.add_field(&field.value) as u16;
+
+                self.emit(OP_GET_CLASS_MEMBER, vec![field_index]);
 
                 Ok(())
             }
@@ -470,12 +607,18 @@ impl Compiler {
                 self,
                 Node::Expression(Expression::FunctionExpression(expr)),
             ),
+            Expression::FormatStringLiteral(expr) => {
+                compile_format_string(self, Node::Expression(Expression::FormatStringLiteral(expr)))
+            }
             Expression::HashLiteral(expr) => {
                 compile_hash_literal(self, Node::Expression(Expression::HashLiteral(expr)))
             }
             Expression::IfExpression(expr) => {
                 compile_if_expression(self, Node::Expression(Expression::IfExpression(expr)))
             }
+            Expression::MatchExpression(expr) => {
+                compile_match_expression(self, Node::Expression(Expression::MatchExpression(expr)))
+            }
             Expression::InfixExpression(expr) => {
                 compile_infix_expression(self, Node::Expression(Expression::InfixExpression(expr)))
             }
@@ -491,6 +634,37 @@ impl Compiler {
         }
     }
 
+    // 离开 try 块前由内到外弹出 count 个异常处理器, 并执行对应的 finally 块
+    fn compile_try_exits(&mut self, count: usize) -> Result<(), CompileError> {
+        let blocks = self.scopes[self.scope_index].try_finally_blocks.clone();
+        let try_depth_in_loop = self.try_depth_in_loop;
+
+        let start = blocks.len().saturating_sub(count);
+
+        for (exited, index) in (start..blocks.len()).rev().enumerate() {
+            self.emit(OP_POP_TRY, vec![]);
+
+            if let Some(finally_block) = &blocks[index] {
+                // finally 块只处于更外层的 try 块中
+                self.scopes[self.scope_index].try_finally_blocks.truncate(index);
+                self.try_depth_in_loop = try_depth_in_loop.saturating_sub(exited + 1);
+
+                let result = self.compile_stmt(Statement::BlockStatement(finally_block.clone()));
+
+                self.scopes[self.scope_index].try_finally_blocks = blocks.clone();
+                self.try_depth_in_loop = try_depth_in_loop;
+
+                if let Err(msg) = result {
+                    return Err(CompileError::from_none_token(format!(
+                        "error compile finally body: \n{msg}"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn compile_stmt(&mut self, node: Statement) -> Result<(), CompileError> {
         match node {
             Statement::BlockStatement(block) => {
@@ -502,6 +676,8 @@ impl Compiler {
             }
 
             Statement::BreakStatement(_) => {
+                self.compile_try_exits(self.try_depth_in_loop)?;
+
                 let pos = self.emit(OP_JUMP, vec![FAKE_OFFSET_JUMP]);
                 self.break_command_pos.push(pos);
 
@@ -509,6 +685,8 @@ impl Compiler {
             }
 
             Statement::ContinueStatement(_) => {
+                self.compile_try_exits(self.try_depth_in_loop)?;
+
                 let pos = self.emit(OP_JUMP, vec![FAKE_OFFSET_JUMP]);
                 self.continue_command_pos.push(pos);
 
@@ -540,7 +718,13 @@ impl Compiler {
             }
 
             Statement::LetStatement(let_stmt) => {
-                let symbol = self.symbol_table.borrow_mut().define(&let_stmt.name.value);
+                self.check_redeclare(&let_stmt.name.value, Some(&let_stmt.name.token))?;
+
+                let symbol = if let_stmt.readonly {
+                    self.symbol_table.borrow_mut().define_readonly(&let_stmt.name.value)
+                } else {
+                    self.symbol_table.borrow_mut().define(&let_stmt.name.value)
+                };
 
                 let result = self.compile_expr(*let_stmt.value);
 
@@ -577,11 +761,37 @@ impl Compiler {
                     )));
                 }
 
+                // 返回值留在栈上, 先执行外层的 finally 块
+                let try_count = self.scopes[self.scope_index].try_finally_blocks.len();
+                self.compile_try_exits(try_count)?;
+
                 self.emit(OP_RETURN_VALUE, vec![]);
 
                 Ok(())
             }
 
+            Statement::YieldStatement(yield_stmt) => {
+                if self.symbol_table.borrow().outer.is_none() {
+                    return Err(CompileError::from(
+                        String::from("cannot yield outside function"),
+                        Some(yield_stmt.token()),
+                    ));
+                }
+
+                if let Err(msg) = self.compile_expr(*yield_stmt.value) {
+                    return Err(CompileError::from_none_token(format!(
+                        "error compile yield value: {msg}"
+                    )));
+                }
+
+                // 含有 yield 的函数在调用时返回生成器
+                self.scopes[self.scope_index].is_generator = true;
+
+                self.emit(OP_YIELD, vec![]);
+
+                Ok(())
+            }
+
             Statement::UseStatement(use_statement) => {
                 let mod_name_index = self.add_constant(Object::AntString(AntString::new(
                     use_statement.name.value.clone(),
@@ -595,7 +805,7 @@ impl Compiler {
                     &use_statement.name.value
                 };
 
-                let symbol = self.symbol_table.borrow_mut().define(&name);
+                let symbol = self.define_symbol(name, Some(&use_statement.name.token))?;
 
                 self.emit(
                     if self.symbol_table.borrow().outer.is_none() {
@@ -609,6 +819,39 @@ impl Compiler {
                 Ok(())
             }
 
+            Statement::ImportStatement(import_statement) => {
+                let mod_name_index = self.add_constant(Object::AntString(AntString::new(
+                    import_statement.module_path(),
+                )));
+
+                self.emit(OP_LOAD_MODULE, vec![mod_name_index as u16]);
+
+                // import a.b.c 绑定为 c
+                let name = if let Some(name) = &import_statement.alias {
+                    &name.value
+                } else {
+                    &import_statement.path.last().expect("expected an module name").value
+                };
+
+                let symbol = self.define_symbol(name, Some(&import_statement.token))?;
+
+                self.emit(
+                    if symbol.scope == SymbolScope::Global {
+                        OP_SET_GLOBAL
+                    } else {
+                        OP_SET_LOCAL
+                    },
+                    vec![symbol.index as u16],
+                );
+
+                Ok(())
+            }
+
+            Statement::FromImportStatement(stmt) => compile_from_import_statement(
+                self,
+                Node::Statement(Statement::FromImportStatement(stmt)),
+            ),
+
             Statement::TestPrintStatement(test_print_stmt) => {
                 if let Err(msg) = self.compile_expr(*test_print_stmt.value) {
                     return Err(CompileError::from_none_token(format!(
@@ -624,9 +867,26 @@ impl Compiler {
             Statement::ClassStatement(stmt) => {
                 compile_class(self, Node::Statement(Statement::ClassStatement(stmt)))
             }
+            Statement::EnumStatement(stmt) => {
+                compile_enum_statement(self, Node::Statement(Statement::EnumStatement(stmt)))
+            }
             Statement::WhileStatement(stmt) => {
                 compile_while_statement(self, Node::Statement(Statement::WhileStatement(stmt)))
             }
+            Statement::DestructuringLetStatement(stmt) => compile_destructuring_let(
+                self,
+                Node::Statement(Statement::DestructuringLetStatement(stmt)),
+            ),
+            Statement::ForStatement(stmt) => {
+                compile_for_statement(self, Node::Statement(Statement::ForStatement(stmt)))
+            }
+            Statement::TryStatement(stmt) => {
+                compile_try_statement(self, Node::Statement(Statement::TryStatement(stmt)))
+            }
+            Statement::VisibilityStatement(stmt) => compile_visibility_statement(
+                self,
+                Node::Statement(Statement::VisibilityStatement(stmt)),
+            ),
         }
     }
 
@@ -641,6 +901,80 @@ impl Compiler {
         };
     }
 
+    // 在类中通过 self 访问成员时, 返回当前类名常量的下标 (允许访问该类的私有成员)
+    pub fn self_access_class(&mut self, obj: &Expression) -> Option<u16> {
+        let class_name = self.class_names.last()?.clone();
+
+        match obj {
+            Expression::Identifier(it) if it.value == "self" => {
+                Some(self.add_constant(Object::AntString(AntString::new(class_name))) as u16)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn emit_get_field(&mut self, field_index: u16, self_class: Option<u16>) {
+        match self_class {
+            Some(class_index) => self.emit(OP_GET_SELF_FIELD, vec![field_index, class_index]),
+            None => self.emit(OP_GET_FIELD, vec![field_index]),
+        };
+    }
+
+    pub fn emit_set_field(&mut self, field_index: u16, self_class: Option<u16>) {
+        match self_class {
+            Some(class_index) => self.emit(OP_SET_SELF_FIELD, vec![field_index, class_index]),
+            None => self.emit(OP_SET_FIELD, vec![field_index]),
+        };
+    }
+
+    // 为赋值表达式写回变量
+    #[inline(always)]
+    // 不能在同一作用域中重新定义只读 (const / readonly let) 符号
+    pub fn check_redeclare(&self, name: &str, token: Option<&Token>) -> Result<(), CompileError> {
+        let redeclare_readonly = self
+            .symbol_table
+            .borrow()
+            .store
+            .get(name)
+            .is_some_and(|it| it.readonly && it.scope != SymbolScope::Free);
+
+        if redeclare_readonly {
+            return Err(CompileError::from(
+                format!("cannot redeclare readonly variable: {name}."),
+                token.cloned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // 所有引入新绑定的地方 (let, 解构, for, catch, 函数, 类, 导入...) 都经由此定义符号
+    pub fn define_symbol(&mut self, name: &str, token: Option<&Token>) -> Result<Symbol, CompileError> {
+        self.check_redeclare(name, token)?;
+
+        Ok(self.symbol_table.borrow_mut().define(name))
+    }
+
+    pub fn store_symbol(&mut self, symbol: &Symbol) {
+        match symbol.scope {
+            SymbolScope::Global => self.emit(OP_SET_GLOBAL, vec![symbol.index as u16]),
+            SymbolScope::Free => self.emit(OP_SET_FREE, vec![symbol.index as u16]),
+            _ => self.emit(OP_SET_LOCAL, vec![symbol.index as u16]),
+        };
+    }
+
+    // 自由变量在外层作用域中的来源, 由 OpClosure 按此捕获
+    pub fn capture_symbols(free_symbols: &[Symbol]) -> Rc<[Capture]> {
+        free_symbols
+            .iter()
+            .map(|symbol| match symbol.scope {
+                SymbolScope::Free => Capture::Free(symbol.index),
+                SymbolScope::Function => Capture::CurrentClosure,
+                _ => Capture::Local(symbol.index),
+            })
+            .collect()
+    }
+
     pub fn enter_scope(&mut self, scope_info: ScopeInfo) {
         self.symbol_table = rc_ref_cell!(SymbolTable::with_outer(self.symbol_table.clone()));
 
@@ -688,7 +1022,7 @@ impl Compiler {
         self.scopes[self.scope_index].instructions.clone()
     }
 
-    fn replace_instruction(&mut self, pos: usize, new_instruction: Instructions) {
+    pub fn replace_instruction(&mut self, pos: usize, new_instruction: Instructions) {
         let current_instructions = self.current_instructions();
 
         let mut target = current_instructions.borrow_mut(); // 获取可变引用
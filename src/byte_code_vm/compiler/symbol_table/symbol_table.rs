@@ -17,11 +17,13 @@ pub struct Symbol {
     pub name: String,
     pub scope: SymbolScope,
     pub index: usize,
+    pub readonly: bool, // const / readonly let 定义的符号不能被重新赋值
+    pub private: bool,  // 类中 private 修饰的成员
 }
 
 impl Symbol {
     pub fn new(name: String, scope: SymbolScope, index: usize) -> Self {
-        Self { name, scope, index }
+        Self { name, scope, index, readonly: false, private: false }
     }
 }
 
@@ -93,6 +95,15 @@ impl SymbolTable {
         None
     }
 
+    pub fn define_readonly(&mut self, name: &str) -> Symbol {
+        let mut symbol = self.define(name);
+        symbol.readonly = true;
+
+        self.store.insert(name.into(), symbol.clone());
+
+        symbol
+    }
+
     // this function has bug
     pub fn define_free(&mut self, original: Symbol) -> Symbol {
         self.free_symbols.push(original.clone());
@@ -10,7 +10,7 @@ mod tests {
     use crate::{
         big_dec, byte_code_vm::{
             code::code::{
-                instruction_to_str, make, Instructions, OP_ADD, OP_ARRAY, OP_BANG, OP_CONSTANTS, OP_DIVIDE, OP_EQ, OP_FALSE, OP_GET_GLOBAL, OP_GT, OP_HASH, OP_INDEX, OP_JUMP, OP_JUMP_NOT_TRUTHY, OP_MINUS, OP_MULTIPLY, OP_NONE, OP_NOTEQ, OP_POP, OP_SET_GLOBAL, OP_SUBTRACT, OP_TRUE
+                instruction_to_str, make, Instructions, OP_ADD, OP_ARRAY, OP_BANG, OP_CONSTANTS, OP_DIVIDE, OP_DUP, OP_EQ, OP_FALSE, OP_FLOOR_DIV, OP_GET_GLOBAL, OP_GE, OP_GET_ITER, OP_GT, OP_HASH, OP_INDEX, OP_ITER_NEXT, OP_JUMP, OP_JUMP_IF_FALSE_OR_POP, OP_JUMP_IF_TRUE_OR_POP, OP_JUMP_NOT_TRUTHY, OP_LE, OP_LT, OP_MINUS, OP_MOD, OP_MULTIPLY, OP_NONE, OP_NOTEQ, OP_POP, OP_POP_TRY, OP_POW, OP_REVERSE, OP_SET_GLOBAL, OP_SET_INDEX, OP_SETUP_TRY, OP_SUBTRACT, OP_TRUE
             },
             compiler::compiler::Compiler, scope_info::ScopeInfo,
         }, convert_type_use_box, obj_enum::object::Object, object::{ant_int::AntInt, ant_string::AntString}, parser::utils::parse
@@ -89,6 +89,30 @@ mod tests {
                     make(OP_POP, &vec![]),
                 ],
             ),
+            CompilerTestCase::new(
+                "1 % 2 ~/ 3".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2)), Box::new(big_dec!(3))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0u16]),
+                    make(OP_CONSTANTS, &vec![1u16]),
+                    make(OP_MOD, &vec![]),
+                    make(OP_CONSTANTS, &vec![2u16]),
+                    make(OP_FLOOR_DIV, &vec![]),
+                    make(OP_POP, &vec![]),
+                ],
+            ),
+            CompilerTestCase::new(
+                "1 ** 2 ** 3".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2)), Box::new(big_dec!(3))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0u16]),
+                    make(OP_CONSTANTS, &vec![1u16]),
+                    make(OP_CONSTANTS, &vec![2u16]),
+                    make(OP_POW, &vec![]),
+                    make(OP_POW, &vec![]),
+                    make(OP_POP, &vec![]),
+                ],
+            ),
             CompilerTestCase::<Box<BigDecimal>>::new(
                 "-1".into(),
                 vec![Box::new(big_dec!(1))],
@@ -193,6 +217,42 @@ mod tests {
         run_compiler_tests(tests)
     }
 
+    #[test]
+    fn test_logical_operators() {
+        let tests = vec![
+            CompilerTestCase::<Box<BigDecimal>>::new(
+                "true and false".into(),
+                vec![],
+                vec![
+                    // 0000
+                    make(OP_TRUE, &vec![]),
+                    // 0001
+                    make(OP_JUMP_IF_FALSE_OR_POP, &vec![5]),
+                    // 0004
+                    make(OP_FALSE, &vec![]),
+                    // 0005
+                    make(OP_POP, &vec![]),
+                ],
+            ),
+            CompilerTestCase::<Box<BigDecimal>>::new(
+                "false or true".into(),
+                vec![],
+                vec![
+                    // 0000
+                    make(OP_FALSE, &vec![]),
+                    // 0001
+                    make(OP_JUMP_IF_TRUE_OR_POP, &vec![5]),
+                    // 0004
+                    make(OP_TRUE, &vec![]),
+                    // 0005
+                    make(OP_POP, &vec![]),
+                ],
+            ),
+        ];
+
+        run_compiler_tests(tests)
+    }
+
     #[test]
     fn test_boolean_expressions() {
         let tests = vec![
@@ -218,11 +278,31 @@ mod tests {
             ),
             CompilerTestCase::new(
                 "1 < 2".into(),
-                vec![Box::new(big_dec!(2)), Box::new(big_dec!(1))],
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2))],
                 vec![
                     make(OP_CONSTANTS, &vec![0u16]),
                     make(OP_CONSTANTS, &vec![1u16]),
-                    make(OP_GT, &vec![]),
+                    make(OP_LT, &vec![]),
+                    make(OP_POP, &vec![]),
+                ],
+            ),
+            CompilerTestCase::new(
+                "1 <= 2".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0u16]),
+                    make(OP_CONSTANTS, &vec![1u16]),
+                    make(OP_LE, &vec![]),
+                    make(OP_POP, &vec![]),
+                ],
+            ),
+            CompilerTestCase::new(
+                "1 >= 2".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0u16]),
+                    make(OP_CONSTANTS, &vec![1u16]),
+                    make(OP_GE, &vec![]),
                     make(OP_POP, &vec![]),
                 ],
             ),
@@ -408,6 +488,101 @@ mod tests {
         run_compiler_tests(tests)
     }
 
+    #[test]
+    fn test_for_statement() {
+        let tests = vec![CompilerTestCase::new(
+            "for x in [1] { x }".into(),
+            vec![Box::new(big_dec!(1))],
+            vec![
+                // 0000
+                make(OP_CONSTANTS, &vec![0u16]),
+                // 0003
+                make(OP_ARRAY, &vec![1u16]),
+                // 0006
+                make(OP_GET_ITER, &vec![]),
+                // 0007
+                make(OP_ITER_NEXT, &vec![20u16]),
+                // 0010
+                make(OP_SET_GLOBAL, &vec![0u16]),
+                // 0013
+                make(OP_GET_GLOBAL, &vec![0u16]),
+                // 0016
+                make(OP_POP, &vec![]),
+                // 0017
+                make(OP_JUMP, &vec![7u16]),
+                // 0020
+                make(OP_POP, &vec![]), // 弹出迭代器
+            ],
+        )];
+
+        run_compiler_tests(tests)
+    }
+
+    #[test]
+    fn test_try_statement() {
+        let tests = vec![CompilerTestCase::new(
+            "try { 1 } catch e { 2 }".into(),
+            vec![Box::new(big_dec!(1)), Box::new(big_dec!(2))],
+            vec![
+                // 0000
+                make(OP_SETUP_TRY, &vec![11u16]),
+                // 0003
+                make(OP_CONSTANTS, &vec![0u16]),
+                // 0006
+                make(OP_POP, &vec![]),
+                // 0007
+                make(OP_POP_TRY, &vec![]),
+                // 0008
+                make(OP_JUMP, &vec![18u16]),
+                // 0011
+                make(OP_SET_GLOBAL, &vec![0u16]), // 绑定错误对象
+                // 0014
+                make(OP_CONSTANTS, &vec![1u16]),
+                // 0017
+                make(OP_POP, &vec![]),
+            ],
+        )];
+
+        run_compiler_tests(tests)
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let tests = vec![
+            CompilerTestCase::new(
+                "let a = 1; a += 2".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(2))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0]),
+                    make(OP_SET_GLOBAL, &vec![0]),
+                    make(OP_GET_GLOBAL, &vec![0]),
+                    make(OP_CONSTANTS, &vec![1]),
+                    make(OP_ADD, &vec![]),
+                    make(OP_SET_GLOBAL, &vec![0]),
+                ],
+            ),
+            CompilerTestCase::new(
+                "let a = [1]; a[0] -= 2".into(),
+                vec![Box::new(big_dec!(1)), Box::new(big_dec!(0)), Box::new(big_dec!(2))],
+                vec![
+                    make(OP_CONSTANTS, &vec![0]),
+                    make(OP_ARRAY, &vec![1]),
+                    make(OP_SET_GLOBAL, &vec![0]),
+                    make(OP_GET_GLOBAL, &vec![0]),
+                    make(OP_CONSTANTS, &vec![1]),
+                    make(OP_DUP, &vec![2]),
+                    make(OP_INDEX, &vec![]),
+                    make(OP_CONSTANTS, &vec![2]),
+                    make(OP_SUBTRACT, &vec![]),
+                    make(OP_REVERSE, &vec![3]),
+                    make(OP_SET_INDEX, &vec![]),
+                ],
+            ),
+        ];
+
+        run_compiler_tests(tests)
+    }
+
     #[test]
     fn test_string_expressions() {
         let tests = vec![
@@ -2,11 +2,39 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::{obj_enum::object::Object, object::ant_class::AntClass};
 
+// 检查是否可以访问对象的成员 field, class_name 为通过 self 访问时所在的类
+pub fn check_field_access(obj: &Object, field: &str, class_name: Option<&str>) -> Result<(), String> {
+    if let Object::AntClass(clazz) = obj
+        && let Some(owner) = clazz.private_owner(field)
+        && class_name != Some(&*owner)
+    {
+        return Err(format!("cannot access private member '{field}' of class {owner}"));
+    }
+
+    Ok(())
+}
+
+// 非接口的类必须定义所有声明为 Uninit 的成员
+pub fn check_members_defined(clazz: &AntClass) -> Result<(), String> {
+    if clazz.interface {
+        return Ok(());
+    }
+
+    match clazz.undefined_member() {
+        Some((field, owner)) => Err(format!(
+            "class '{}' must define member '{field}' declared in '{owner}'",
+            clazz.name
+        )),
+        None => Ok(()),
+    }
+}
+
 pub fn build_class(
     stack: &Vec<Rc<RefCell<Object>>>,
     name: &str,
     start_index: usize,
     end_index: usize,
+    interface: bool,
 ) -> Result<AntClass, String> {
     let mut m = HashMap::with_capacity(end_index - start_index);
 
@@ -23,5 +51,10 @@ pub fn build_class(
         m.insert(key, v);
     }
 
-    Ok(AntClass::from((name, m)))
+    let mut clazz = AntClass::from((name, m));
+    clazz.interface = interface;
+
+    check_members_defined(&clazz)?;
+
+    Ok(clazz)
 }
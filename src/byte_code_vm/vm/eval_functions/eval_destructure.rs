@@ -0,0 +1,68 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    byte_code_vm::vm::eval_functions::eval_match::match_class,
+    obj_enum::object::Object,
+    object::{ant_array::AntArray, object::IAntObject},
+};
+
+pub fn check_array(value: &Object, len: usize, has_rest: bool) -> Result<(), String> {
+    let items_len = match value {
+        Object::AntArray(arr) => arr.items.len(),
+        Object::AntTuple(tuple) => tuple.items.len(),
+        it => return Err(format!("cannot destructure {} as an array", it.get_type())),
+    };
+
+    if has_rest && items_len < len {
+        return Err(format!(
+            "expected at least {len} items to destructure, got {items_len}"
+        ));
+    }
+
+    if !has_rest && items_len != len {
+        return Err(format!("expected {len} items to destructure, got {items_len}"));
+    }
+
+    Ok(())
+}
+
+pub fn check_hash(value: &Object, keys: &[Rc<RefCell<Object>>]) -> Result<(), String> {
+    let map = match value {
+        Object::AntHashMap(map) => map,
+        it => return Err(format!("cannot destructure {} as a hash map", it.get_type())),
+    };
+
+    for key in keys {
+        let key = &*key.borrow();
+
+        if !map.map.contains_key(key) {
+            return Err(format!("missing key \"{}\" to destructure", key.inspect()));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_instance(value: &Object, name: &str) -> Result<(), String> {
+    if !match_class(value, name) {
+        return Err(format!(
+            "cannot destructure {} as an instance of '{name}'",
+            value.inspect()
+        ));
+    }
+
+    Ok(())
+}
+
+// 数组 (或元组) 从 start 开始的剩余元素
+pub fn array_rest(value: &Object, start: usize) -> Result<Object, String> {
+    let items = match value {
+        Object::AntArray(arr) => &arr.items,
+        Object::AntTuple(tuple) => &tuple.items,
+        it => return Err(format!("cannot destructure {} as an array", it.get_type())),
+    };
+
+    Ok(Object::AntArray(AntArray::from(
+        items.iter().skip(start).cloned().collect::<Vec<_>>(),
+    )))
+}
@@ -0,0 +1,130 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    byte_code_vm::{
+        code::code::{
+            OpCode, OP_ADD, OP_BANG, OP_BIT_AND, OP_BIT_NOT, OP_BIT_OR, OP_BIT_XOR, OP_DIVIDE,
+            OP_EQ, OP_FLOOR_DIV, OP_GE, OP_GT, OP_LE, OP_LT, OP_MINUS, OP_MOD, OP_MULTIPLY,
+            OP_NOTEQ, OP_POW, OP_SHL, OP_SHR, OP_SUBTRACT,
+        },
+        utils::native_boolean_to_object,
+        vm::vm::Vm,
+    },
+    function_caller::native_to_call_api::native_to_call,
+    obj_enum::object::Object,
+    object::{ant_method::Method, utils::is_truthy},
+    rc_ref_cell,
+};
+
+// 中缀运算符对应的魔术方法
+pub fn infix_dunder(op: OpCode) -> Option<&'static str> {
+    Some(match op {
+        OP_ADD => "__add__",
+        OP_SUBTRACT => "__sub__",
+        OP_MULTIPLY => "__mul__",
+        OP_DIVIDE => "__div__",
+        OP_MOD => "__mod__",
+        OP_FLOOR_DIV => "__floordiv__",
+        OP_POW => "__pow__",
+        OP_BIT_AND => "__and__",
+        OP_BIT_OR => "__or__",
+        OP_BIT_XOR => "__xor__",
+        OP_SHL => "__lshift__",
+        OP_SHR => "__rshift__",
+        OP_EQ => "__eq__",
+        OP_NOTEQ => "__ne__",
+        OP_LT => "__lt__",
+        OP_GT => "__gt__",
+        OP_LE => "__le__",
+        OP_GE => "__ge__",
+        _ => return None,
+    })
+}
+
+// 前缀运算符对应的魔术方法
+pub fn prefix_dunder(op: OpCode) -> Option<&'static str> {
+    Some(match op {
+        OP_MINUS => "__neg__",
+        OP_BANG => "__not__",
+        OP_BIT_NOT => "__invert__",
+        _ => return None,
+    })
+}
+
+// 若 obj 为定义了魔术方法 name 的类实例, 返回绑定了 self 的该方法
+pub fn bind_dunder(obj: &Rc<RefCell<Object>>, name: &str) -> Option<Method> {
+    let mut method = match &*obj.borrow() {
+        Object::AntClass(clazz) => match clazz.get_member(name) {
+            Some(Object::Method(method)) => method,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    method.me = Some(obj.clone());
+
+    Some(method)
+}
+
+// 若 obj 定义了魔术方法 name, 则以 obj 为 self 调用, 返回值留在栈上
+pub fn call_dunder(
+    vm: &mut Vm,
+    obj: &Rc<RefCell<Object>>,
+    name: &str,
+    args: Vec<Rc<RefCell<Object>>>,
+) -> Result<bool, String> {
+    let method = match bind_dunder(obj, name) {
+        Some(it) => it,
+        None => return Ok(false),
+    };
+
+    native_to_call(vm, rc_ref_cell!(Object::Method(method)), args)?;
+
+    Ok(true)
+}
+
+// 与 call_dunder 相同, 但丢弃返回值 (用于 __set__ 与 __setindex__)
+pub fn call_dunder_discard(
+    vm: &mut Vm,
+    obj: &Rc<RefCell<Object>>,
+    name: &str,
+    args: Vec<Rc<RefCell<Object>>>,
+) -> Result<bool, String> {
+    let sp = vm.sp;
+    let called = call_dunder(vm, obj, name, args)?;
+
+    vm.sp = sp;
+
+    Ok(called)
+}
+
+// 左操作数为类实例时分派到魔术方法, 未定义 __ne__ 时取 __eq__ 的相反值
+pub fn eval_infix_dunder(
+    vm: &mut Vm,
+    op: OpCode,
+    left: &Rc<RefCell<Object>>,
+    right: &Rc<RefCell<Object>>,
+) -> Result<bool, String> {
+    let name = match infix_dunder(op) {
+        Some(it) => it,
+        None => return Ok(false),
+    };
+
+    if call_dunder(vm, left, name, vec![right.clone()])? {
+        return Ok(true);
+    }
+
+    if op != OP_NOTEQ || !call_dunder(vm, left, "__eq__", vec![right.clone()])? {
+        return Ok(false);
+    }
+
+    let eq = match vm.pop() {
+        Some(it) => it,
+        None => return Err(String::from("expected the result of __eq__")),
+    };
+    let ne = native_boolean_to_object(!is_truthy(&eq.borrow()));
+
+    vm.push(rc_ref_cell!(ne))?;
+
+    Ok(true)
+}
@@ -0,0 +1,192 @@
+use bigdecimal::{BigDecimal, RoundingMode, num_bigint::ToBigInt};
+
+use crate::{obj_enum::object::Object, object::object::IAntObject};
+
+// 格式说明符: [[fill]align][sign][0][width][.precision][type]
+struct FormatSpec {
+    fill: char,
+    align: Option<char>,
+    sign: char,
+    width: usize,
+    precision: Option<usize>,
+    ty: Option<char>,
+}
+
+fn parse_format_spec(spec: &str) -> Result<FormatSpec, String> {
+    let err = || format!("invalid format specifier '{spec}'");
+
+    let chars = spec.chars().collect::<Vec<char>>();
+    let is_align = |c: Option<&char>| matches!(c, Some('<' | '>' | '^'));
+
+    let mut format_spec = FormatSpec {
+        fill: ' ',
+        align: None,
+        sign: '-',
+        width: 0,
+        precision: None,
+        ty: None,
+    };
+
+    let mut i = 0;
+
+    if is_align(chars.get(1)) {
+        format_spec.fill = chars[0];
+        format_spec.align = Some(chars[1]);
+        i = 2;
+    } else if is_align(chars.first()) {
+        format_spec.align = Some(chars[0]);
+        i = 1;
+    }
+
+    if let Some(c @ ('+' | '-' | ' ')) = chars.get(i) {
+        format_spec.sign = *c;
+        i += 1;
+    }
+
+    // '0' 标志: 在符号之后用 0 填充
+    if chars.get(i) == Some(&'0') && format_spec.align.is_none() {
+        format_spec.fill = '0';
+        format_spec.align = Some('=');
+        i += 1;
+    }
+
+    let read_number = |i: &mut usize| {
+        let start = *i;
+        while chars.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+            *i += 1;
+        }
+
+        chars[start..*i].iter().collect::<String>()
+    };
+
+    let width = read_number(&mut i);
+    if !width.is_empty() {
+        format_spec.width = width.parse().map_err(|_| err())?;
+    }
+
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+
+        let precision = read_number(&mut i);
+        if precision.is_empty() {
+            return Err(err());
+        }
+
+        format_spec.precision = Some(precision.parse().map_err(|_| err())?);
+    }
+
+    if let Some(c @ ('f' | '%' | 'd' | 'x' | 'X' | 'o' | 'b' | 's')) = chars.get(i) {
+        format_spec.ty = Some(*c);
+        i += 1;
+    }
+
+    if i != chars.len() {
+        return Err(err());
+    }
+
+    Ok(format_spec)
+}
+
+fn format_fixed(value: &BigDecimal, precision: usize) -> String {
+    value
+        .with_scale_round(precision as i64, RoundingMode::HalfEven)
+        .to_plain_string()
+}
+
+fn format_number(obj: &Object, format_spec: &FormatSpec) -> Option<String> {
+    let value = match obj {
+        Object::AntInt(it) => it.value.clone(),
+        Object::AntI64(it) => BigDecimal::from(it.value),
+        Object::AntDouble(it) => it.value.clone(),
+        _ => return None,
+    };
+
+    let is_integer = !matches!(obj, Object::AntDouble(_));
+
+    let s = match (format_spec.ty, format_spec.precision) {
+        (Some('f'), precision) => format_fixed(&value, precision.unwrap_or(6)),
+        (Some('%'), precision) => format!("{}%", format_fixed(&(value * 100), precision.unwrap_or(6))),
+
+        (Some('d' | 'x' | 'X' | 'o' | 'b'), _) if is_integer => {
+            let n = value.to_bigint()?;
+
+            match format_spec.ty {
+                Some('x') => format!("{n:x}"),
+                Some('X') => format!("{n:X}"),
+                Some('o') => format!("{n:o}"),
+                Some('b') => format!("{n:b}"),
+                _ => n.to_string(),
+            }
+        }
+
+        (None, Some(precision)) => format_fixed(&value, precision),
+        (None, None) => obj.inspect(),
+
+        _ => return None,
+    };
+
+    if !s.starts_with('-') && format_spec.sign != '-' {
+        return Some(format!("{}{s}", format_spec.sign));
+    }
+
+    Some(s)
+}
+
+fn pad(s: String, format_spec: &FormatSpec, default_align: char) -> String {
+    let len = s.chars().count();
+    if len >= format_spec.width {
+        return s;
+    }
+
+    let count = format_spec.width - len;
+    let fill = |n: usize| format_spec.fill.to_string().repeat(n);
+
+    match format_spec.align.unwrap_or(default_align) {
+        '<' => format!("{s}{}", fill(count)),
+        '^' => format!("{}{s}{}", fill(count / 2), fill(count - count / 2)),
+
+        // 在符号之后填充
+        '=' => match s.chars().next() {
+            Some(sign @ ('+' | '-' | ' ')) => format!("{sign}{}{}", fill(count), &s[1..]),
+            _ => format!("{}{s}", fill(count)),
+        },
+
+        _ => format!("{}{s}", fill(count)),
+    }
+}
+
+pub fn format_value(obj: &Object, spec: &str) -> Result<String, String> {
+    if spec.is_empty() {
+        return Ok(obj.inspect());
+    }
+
+    let format_spec = parse_format_spec(spec)?;
+
+    let unsupported = || {
+        format!(
+            "unsupported format specifier '{spec}' for type: {}",
+            obj.get_type()
+        )
+    };
+
+    let is_number = matches!(obj, Object::AntInt(_) | Object::AntI64(_) | Object::AntDouble(_));
+
+    if is_number && format_spec.ty != Some('s') {
+        let s = format_number(obj, &format_spec).ok_or_else(unsupported)?;
+
+        return Ok(pad(s, &format_spec, '>'));
+    }
+
+    // 非数值: 只允许宽度, 对齐与精度 (截断)
+    if format_spec.sign != '-' || format_spec.align == Some('=') || !matches!(format_spec.ty, None | Some('s')) {
+        return Err(unsupported());
+    }
+
+    let mut s = obj.inspect();
+
+    if let Some(precision) = format_spec.precision {
+        s = s.chars().take(precision).collect();
+    }
+
+    Ok(pad(s, &format_spec, '<'))
+}
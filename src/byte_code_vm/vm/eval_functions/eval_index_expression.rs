@@ -2,10 +2,11 @@ use num_traits::Signed;
 use num_traits::cast::ToPrimitive;
 
 use crate::{
-    big_dec, obj_enum::object::Object, object::{ant_array::AntArray, ant_int::AntInt, ant_string::AntString, object::{IAntObject, INT, STRING}}, try_unwrap
+    big_dec, obj_enum::object::Object, object::{ant_int::AntInt, ant_string::AntString, object::{IAntObject, INT, STRING}}, try_unwrap
 };
 
-fn eval_array_index_expression(arr: &AntArray, index: &AntInt) -> Result<Object, String> {
+// 数组与元组共用的下标访问, 支持负数下标
+fn eval_items_index_expression(items: &[Object], index: &AntInt, kind: &str) -> Result<Object, String> {
     if !index.value.is_integer() {
         return Err(format!("unsupported array index: {}", index.value));
     }
@@ -13,22 +14,22 @@ fn eval_array_index_expression(arr: &AntArray, index: &AntInt) -> Result<Object,
     let absolute_index = if index.value.is_positive() || index.value == big_dec!(0) {
         &index.value
     } else {
-        &(big_dec!(arr.items.len() as u128) + &index.value)
+        &(big_dec!(items.len() as u128) + &index.value)
     };
 
     if absolute_index >= &big_dec!(usize::MAX as u128) {
         return Err(format!("index too big! index: {}", absolute_index));
     }
 
-    if absolute_index >= &big_dec!(arr.items.len() as u128) || absolute_index < &big_dec!(0) {
+    if absolute_index >= &big_dec!(items.len() as u128) || absolute_index < &big_dec!(0) {
         return Err(format!(
-            "index out of range, index: {}, array length: {}",
+            "index out of range, index: {}, {kind} length: {}",
             index.value,
-            arr.items.len()
+            items.len()
         ));
     }
 
-    Ok(arr.items[absolute_index.to_usize().unwrap()].clone())
+    Ok(items[absolute_index.to_usize().unwrap()].clone())
 }
 
 pub fn eval_index_expression(obj: Object, index: Object) -> Result<Object, String> {
@@ -46,9 +47,14 @@ pub fn eval_index_expression(obj: Object, index: Object) -> Result<Object, Strin
                 i.unwrap()
             };
                 
-            eval_array_index_expression(&arr, &index)
+            eval_items_index_expression(&arr.items, &index, "array")
         }
 
+        Object::AntTuple(tuple) => match index {
+            Object::AntInt(index) => eval_items_index_expression(&tuple.items, &index, "tuple"),
+            it => Err(format!("tuple indices must be {INT}, not {}", it.get_type())),
+        },
+
         Object::AntHashMap(map) => {
             let result = map.map.get(&index);
             if let Some(o) = result {
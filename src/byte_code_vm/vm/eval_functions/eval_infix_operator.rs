@@ -1,10 +1,17 @@
 use std::{cell::RefCell, rc::Rc};
 
-use bigdecimal::BigDecimal;
+use bigdecimal::{
+    BigDecimal, RoundingMode,
+    num_bigint::{BigInt, Sign, ToBigInt},
+};
+use num_traits::{ToPrimitive, Zero};
 
 use crate::{
     byte_code_vm::{
-        code::code::{OpCode, OP_ADD, OP_DIVIDE, OP_EQ, OP_GT, OP_MULTIPLY, OP_NOTEQ, OP_SUBTRACT},
+        code::code::{
+            OpCode, OP_ADD, OP_DIVIDE, OP_EQ, OP_FLOOR_DIV, OP_GE, OP_GT, OP_LE, OP_LT, OP_MOD, OP_MULTIPLY, OP_NOTEQ,
+            OP_POW, OP_SUBTRACT, OP_BIT_AND, OP_BIT_OR, OP_BIT_XOR, OP_SHL, OP_SHR,
+        },
         constants::{FALSE_OBJ, TRUE_OBJ},
         utils::native_boolean_to_object,
     },
@@ -253,6 +260,326 @@ fn divide_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Resul
     }
 }
 
+// 向下取整的除法与取模 (余数的符号与除数相同)
+fn floor_div_mod_bigint(l: &BigInt, r: &BigInt) -> (BigInt, BigInt) {
+    let q = l / r;
+    let m = l % r;
+
+    if !m.is_zero() && m.sign() != r.sign() {
+        (q - 1, m + r)
+    } else {
+        (q, m)
+    }
+}
+
+fn floor_div_mod_i64(l: i64, r: i64) -> Result<(i64, i64), String> {
+    let (q, m) = match (l.checked_div(r), l.checked_rem(r)) {
+        (Some(q), Some(m)) => (q, m),
+        _ => return Err("integer overflow".to_string()),
+    };
+
+    if m != 0 && (m < 0) != (r < 0) {
+        Ok((q - 1, m + r))
+    } else {
+        Ok((q, m))
+    }
+}
+
+fn floor_div_mod_decimal(l: &BigDecimal, r: &BigDecimal) -> (BigDecimal, BigDecimal) {
+    let mut m = l % r;
+
+    if !m.is_zero() && m.sign() != r.sign() {
+        m += r;
+    }
+
+    let q = ((l - &m) / r).with_scale_round(0, RoundingMode::HalfEven);
+
+    (q, m)
+}
+
+fn bigdecimal_to_bigint(value: &BigDecimal) -> BigInt {
+    value.to_bigint().unwrap_or_default()
+}
+
+fn mod_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    match (left, right) {
+        (Object::AntInt(l), Object::AntInt(r)) => {
+            if r.value.is_zero() {
+                return Err("modulo by zero".to_string());
+            }
+
+            let (_, m) =
+                floor_div_mod_bigint(&bigdecimal_to_bigint(&l.value), &bigdecimal_to_bigint(&r.value));
+
+            Ok(Object::AntInt(AntInt::from(BigDecimal::from(m))))
+        }
+        (Object::AntI64(l), Object::AntI64(r)) => {
+            if r.value == 0 {
+                return Err("modulo by zero".to_string());
+            }
+
+            let (_, m) = floor_div_mod_i64(l.value, r.value)?;
+
+            Ok(Object::AntI64(AntI64::from(m)))
+        }
+        (Object::AntDouble(_) | Object::AntInt(_), Object::AntDouble(_) | Object::AntInt(_)) => {
+            let (l, r) = (decimal_value(left), decimal_value(right));
+
+            if r.is_zero() {
+                return Err("modulo by zero".to_string());
+            }
+
+            let (_, m) = floor_div_mod_decimal(l, r);
+
+            Ok(Object::AntDouble(AntDouble::from(m)))
+        }
+
+        (l, r) => Err(format!(
+            "unimplemented for types: {} and {}",
+            l.get_type(),
+            r.get_type()
+        )),
+    }
+}
+
+fn floor_div_native(
+    left: Rc<RefCell<Object>>,
+    right: Rc<RefCell<Object>>,
+) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    match (left, right) {
+        (Object::AntInt(l), Object::AntInt(r)) => {
+            if r.value.is_zero() {
+                return Err("division by zero".to_string());
+            }
+
+            let (q, _) =
+                floor_div_mod_bigint(&bigdecimal_to_bigint(&l.value), &bigdecimal_to_bigint(&r.value));
+
+            Ok(Object::AntInt(AntInt::from(BigDecimal::from(q))))
+        }
+        (Object::AntI64(l), Object::AntI64(r)) => {
+            if r.value == 0 {
+                return Err("division by zero".to_string());
+            }
+
+            let (q, _) = floor_div_mod_i64(l.value, r.value)?;
+
+            Ok(Object::AntI64(AntI64::from(q)))
+        }
+        (Object::AntDouble(_) | Object::AntInt(_), Object::AntDouble(_) | Object::AntInt(_)) => {
+            let (l, r) = (decimal_value(left), decimal_value(right));
+
+            if r.is_zero() {
+                return Err("division by zero".to_string());
+            }
+
+            let (q, _) = floor_div_mod_decimal(l, r);
+
+            Ok(Object::AntDouble(AntDouble::from(q)))
+        }
+
+        (l, r) => Err(format!(
+            "unimplemented for types: {} and {}",
+            l.get_type(),
+            r.get_type()
+        )),
+    }
+}
+
+// 仅用于 AntInt 与 AntDouble
+fn decimal_value(obj: &Object) -> &BigDecimal {
+    match obj {
+        Object::AntInt(it) => &it.value,
+        Object::AntDouble(it) => &it.value,
+        _ => unreachable!(),
+    }
+}
+
+// ** 与 << 结果的最大 (十进制) 位数, 超出时报错, 避免耗尽时间与内存
+const MAX_RESULT_DIGITS: f64 = 100_000.0;
+
+// 估算 base ** exp 的位数 (整数部分与小数部分)
+fn check_pow_size(base: &BigDecimal, exp: u32) -> Result<(), String> {
+    let abs = base.abs();
+
+    let integer_digits = match abs.to_f64() {
+        Some(it) if it > 1.0 => it.log10(),
+        Some(_) => 0.0,
+        None => f64::INFINITY,
+    };
+
+    let (_, scale) = abs.as_bigint_and_exponent();
+
+    if (integer_digits + scale.max(0) as f64) * exp as f64 > MAX_RESULT_DIGITS {
+        return Err("result too large".to_string());
+    }
+
+    Ok(())
+}
+
+// 快速幂, 指数为非负整数
+fn pow_decimal(base: &BigDecimal, exp: u32) -> BigDecimal {
+    let mut result = BigDecimal::from(1);
+    let mut base = base.clone();
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = &result * &base;
+        }
+
+        base = &base * &base;
+        exp >>= 1;
+    }
+
+    result
+}
+
+fn pow_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    match (left, right) {
+        (Object::AntI64(l), Object::AntI64(r)) => {
+            if r.value >= 0 {
+                let exp = u32::try_from(r.value).map_err(|_| "exponent too large".to_string())?;
+
+                return match l.value.checked_pow(exp) {
+                    Some(it) => Ok(Object::AntI64(AntI64::from(it))),
+                    None => Err("integer overflow".to_string()),
+                };
+            }
+
+            if l.value == 0 {
+                return Err("division by zero".to_string());
+            }
+
+            let exp = r.value.unsigned_abs();
+            let exp = u32::try_from(exp).map_err(|_| "exponent too large".to_string())?;
+
+            check_pow_size(&BigDecimal::from(l.value), exp)?;
+
+            Ok(Object::AntDouble(AntDouble::from(
+                BigDecimal::from(1) / pow_decimal(&BigDecimal::from(l.value), exp),
+            )))
+        }
+        (Object::AntDouble(_) | Object::AntInt(_), Object::AntDouble(_) | Object::AntInt(_)) => {
+            let (l, r) = (decimal_value(left), decimal_value(right));
+
+            // 非整数指数: 退化为浮点运算
+            if !r.is_integer() {
+                let result = match (l.to_f64(), r.to_f64()) {
+                    (Some(l), Some(r)) => l.powf(r),
+                    _ => return Err("number too large".to_string()),
+                };
+
+                // 经由字符串转换, 避免引入二进制浮点的误差位
+                return match result.to_string().parse::<BigDecimal>() {
+                    Ok(it) if result.is_finite() => Ok(Object::AntDouble(AntDouble::from(it))),
+                    _ => Err("math domain error".to_string()),
+                };
+            }
+
+            let exp = match r.abs().to_u32() {
+                Some(it) => it,
+                None => return Err("exponent too large".to_string()),
+            };
+
+            check_pow_size(l, exp)?;
+
+            let result = pow_decimal(l, exp);
+
+            if r.sign() == Sign::Minus {
+                if l.is_zero() {
+                    return Err("division by zero".to_string());
+                }
+
+                return Ok(Object::AntDouble(AntDouble::from(BigDecimal::from(1) / result)));
+            }
+
+            if let (Object::AntInt(_), Object::AntInt(_)) = (left, right) {
+                Ok(Object::AntInt(AntInt::from(result)))
+            } else {
+                Ok(Object::AntDouble(AntDouble::from(result)))
+            }
+        }
+
+        (l, r) => Err(format!(
+            "unimplemented for types: {} and {}",
+            l.get_type(),
+            r.get_type()
+        )),
+    }
+}
+
+// AntInt 按无限位宽补码进行位运算
+fn bitwise_native(
+    op: OpCode,
+    left: Rc<RefCell<Object>>,
+    right: Rc<RefCell<Object>>,
+) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    match (left, right) {
+        (Object::AntInt(l), Object::AntInt(r)) => {
+            let (l, r) = (bigdecimal_to_bigint(&l.value), bigdecimal_to_bigint(&r.value));
+
+            let result = match op {
+                OP_BIT_AND => l & r,
+                OP_BIT_OR => l | r,
+                OP_BIT_XOR => l ^ r,
+                _ => {
+                    if r.sign() == Sign::Minus {
+                        return Err("negative shift count".to_string());
+                    }
+
+                    let shift = r.to_usize().ok_or_else(|| "shift count too large".to_string())?;
+
+                    if op == OP_SHL && shift as f64 * std::f64::consts::LOG10_2 > MAX_RESULT_DIGITS {
+                        return Err("shift count too large".to_string());
+                    }
+
+                    if op == OP_SHL { l << shift } else { l >> shift }
+                }
+            };
+
+            Ok(Object::AntInt(AntInt::from(BigDecimal::from(result))))
+        }
+        (Object::AntI64(l), Object::AntI64(r)) => {
+            let (l, r) = (l.value, r.value);
+
+            let result = match op {
+                OP_BIT_AND => l & r,
+                OP_BIT_OR => l | r,
+                OP_BIT_XOR => l ^ r,
+                _ => {
+                    if !(0..64).contains(&r) {
+                        return Err(format!("shift count out of range (0..64): {r}"));
+                    }
+
+                    // << 溢出的高位直接丢弃, >> 为算术右移
+                    if op == OP_SHL { l << r } else { l >> r }
+                }
+            };
+
+            Ok(Object::AntI64(AntI64::from(result)))
+        }
+
+        (l, r) => Err(format!(
+            "unimplemented for types: {} and {}",
+            l.get_type(),
+            r.get_type()
+        )),
+    }
+}
+
 fn gt_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
     let left = &*left.borrow();
     let right = &*right.borrow();
@@ -260,6 +587,33 @@ fn gt_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Ob
     Ok(native_boolean_to_object(gt_native_ref(left, right)?))
 }
 
+// 操作数保持从左到右的求值顺序, 仅在比较时交换
+fn lt_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    Ok(native_boolean_to_object(gt_native_ref(right, left)?))
+}
+
+// a <= b 即 a < b || a == b, 不能比较大小的类型与 < 一样报错
+fn le_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    Ok(native_boolean_to_object(
+        gt_native_ref(right, left)? || eq_native_ref(left, right)?,
+    ))
+}
+
+fn ge_native(left: Rc<RefCell<Object>>, right: Rc<RefCell<Object>>) -> Result<Object, String> {
+    let left = &*left.borrow();
+    let right = &*right.borrow();
+
+    Ok(native_boolean_to_object(
+        gt_native_ref(left, right)? || eq_native_ref(left, right)?,
+    ))
+}
+
 #[inline(always)]
 pub fn gt_native_ref(left: &Object, right: &Object) -> Result<bool, String> {
     match (left, right) {
@@ -292,7 +646,7 @@ pub fn eq_native_ref(left: &Object, right: &Object) -> Result<bool, String> {
     }
 
     if left.get_type() == NULL && right.get_type() == NULL {
-        return Ok(false);
+        return Ok(true);
     }
 
     match (left, right) {
@@ -317,6 +671,14 @@ pub fn eq_native_ref(left: &Object, right: &Object) -> Result<bool, String> {
         (Object::AntString(l), Object::AntString(r)) => {
             Ok(l.value == r.value)
         }
+        (Object::AntTuple(l), Object::AntTuple(r)) => {
+            Ok(l.items == r.items)
+        }
+        (Object::AntEnumValue(l), Object::AntEnumValue(r)) => {
+            Ok(l.same_variant(r) && l.values == r.values)
+        }
+        // 枚举值与其他类型的值总是不相等
+        (Object::AntEnumValue(_), _) | (_, Object::AntEnumValue(_)) => Ok(false),
 
         (l, r) => Err(format!(
             "unimplemented for types: {} and {}",
@@ -361,7 +723,14 @@ pub fn eval_infix_operator(
         OP_SUBTRACT => subtract_native(left, right),
         OP_MULTIPLY => multiply_native(left, right),
         OP_DIVIDE => divide_native(left, right),
+        OP_MOD => mod_native(left, right),
+        OP_FLOOR_DIV => floor_div_native(left, right),
+        OP_POW => pow_native(left, right),
+        OP_BIT_AND | OP_BIT_OR | OP_BIT_XOR | OP_SHL | OP_SHR => bitwise_native(op, left, right),
+        OP_LT => lt_native(left, right),
         OP_GT => gt_native(left, right),
+        OP_LE => le_native(left, right),
+        OP_GE => ge_native(left, right),
         OP_EQ => eq_native(left, right),
         OP_NOTEQ => not_eq_native(left, right),
 
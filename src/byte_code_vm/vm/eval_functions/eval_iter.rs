@@ -0,0 +1,106 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    builtin::builtin_classes::iter_class::create_iter,
+    byte_code_vm::vm::{generator_utils::resume_generator, vm::Vm},
+    function_caller::native_to_call_api::native_to_call,
+    obj_enum::object::Object,
+    object::{ant_string::AntString, object::IAntObject},
+    rc_ref_cell,
+};
+
+/// 将对象转换为迭代器 (拥有 next 方法的类)
+pub fn eval_get_iter(obj: Object) -> Result<Object, String> {
+    match obj {
+        Object::AntClass(clazz) => {
+            if clazz.get_member("next").is_none() {
+                return Err(format!(
+                    "object '{}' is not iterable (no method 'next')",
+                    clazz.inspect()
+                ));
+            }
+
+            Ok(Object::AntClass(clazz))
+        }
+
+        // 生成器本身即是迭代器
+        Object::AntGenerator(generator) => Ok(Object::AntGenerator(generator)),
+
+        Object::AntArray(arr) => Ok(Object::AntClass(create_iter(arr.items))),
+
+        Object::AntTuple(tuple) => Ok(Object::AntClass(create_iter(tuple.items))),
+
+        Object::AntString(s) => Ok(Object::AntClass(create_iter(
+            s.value
+                .chars()
+                .map(|c| Object::AntString(AntString::new(c.to_string())))
+                .collect(),
+        ))),
+
+        Object::AntHashMap(map) => Ok(Object::AntClass(create_iter(
+            map.map.keys().cloned().collect(),
+        ))),
+
+        it => Err(format!("object '{}' is not iterable", it.inspect())),
+    }
+}
+
+/// 调用迭代器的 next 方法
+/// 返回 None 表示迭代结束 (next 返回 Null 或 none)
+pub fn eval_iter_next(
+    vm: &mut Vm,
+    iter: Rc<RefCell<Object>>,
+) -> Result<Option<Rc<RefCell<Object>>>, String> {
+    if matches!(&*iter.borrow(), Object::AntGenerator(_)) {
+        return resume_generator(vm, iter);
+    }
+
+    let next_method = match &*iter.borrow() {
+        Object::AntClass(clazz) => match clazz.get_member("next") {
+            Some(Object::Method(mut method)) => {
+                method.me = Some(iter.clone());
+
+                Object::Method(method)
+            }
+            Some(it) => it,
+            None => {
+                return Err(format!(
+                    "object '{}' has no method 'next'",
+                    clazz.inspect()
+                ));
+            }
+        },
+        it => return Err(format!("expected an iterator, got: {}", it.inspect())),
+    };
+
+    native_to_call(vm, rc_ref_cell!(next_method), vec![])?;
+
+    let result = match vm.pop() {
+        Some(it) => it,
+        None => return Err(String::from("expected an result of method 'next'")),
+    };
+
+    let item = match &*result.borrow() {
+        Object::AntNone(_) => None,
+
+        // Option 类: Some(value) / Null
+        Object::AntClass(clazz) if clazz.map.contains_key("is_null") => {
+            match clazz.map.get("is_null") {
+                Some(Object::AntBoolean(is_null)) if is_null.value => None,
+                _ => match clazz.map.get("value") {
+                    Some(value) => Some(rc_ref_cell!(value.clone())),
+                    None => {
+                        return Err(format!(
+                            "object '{}' has no field 'value'",
+                            clazz.inspect()
+                        ));
+                    }
+                },
+            }
+        }
+
+        it => Some(rc_ref_cell!(it.clone())),
+    };
+
+    Ok(item)
+}
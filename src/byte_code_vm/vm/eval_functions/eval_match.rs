@@ -0,0 +1,90 @@
+use bigdecimal::BigDecimal;
+
+use crate::{
+    byte_code_vm::vm::eval_functions::eval_infix_operator::eq_native_ref,
+    obj_enum::object::Object,
+    object::{ant_class::AntClass, object::IAntObject},
+};
+
+fn number_value(obj: &Object) -> Option<BigDecimal> {
+    match obj {
+        Object::AntInt(it) => Some(it.value.clone()),
+        Object::AntI64(it) => Some(BigDecimal::from(it.value)),
+        Object::AntDouble(it) => Some(it.value.clone()),
+        _ => None,
+    }
+}
+
+// 字面量模式: 类型不同视为不匹配而不是报错
+pub fn match_eq(value: &Object, literal: &Object) -> bool {
+    if let (Some(l), Some(r)) = (number_value(value), number_value(literal)) {
+        return l == r;
+    }
+
+    eq_native_ref(value, literal).unwrap_or(false)
+}
+
+pub fn match_array(value: &Object, len: usize) -> bool {
+    match value {
+        Object::AntArray(arr) => arr.items.len() == len,
+        Object::AntTuple(tuple) => tuple.items.len() == len,
+        _ => false,
+    }
+}
+
+pub fn match_hash(value: &Object) -> bool {
+    matches!(value, Object::AntHashMap(_))
+}
+
+// 类模式: 对象所属的类 (或其父类) 名称相同
+pub fn match_class(value: &Object, name: &str) -> bool {
+    match value {
+        Object::AntClass(clazz) => {
+            if &*clazz.name == name {
+                return true;
+            }
+
+            match &clazz.base {
+                Some(base) => match_class(&base.borrow(), name),
+                None => false,
+            }
+        }
+        // 枚举值可按变体名或枚举名匹配
+        Object::AntEnumValue(value) => {
+            &*value.variant.name == name || &*value.variant.enum_name == name
+        }
+        _ => false,
+    }
+}
+
+// 限定的变体模式 (State.Done): 按 (枚举, 变体序号) 匹配
+pub fn match_variant(value: &Object, enumeration: &Object, name: &str) -> Result<bool, String> {
+    let (enum_name, variants) = match enumeration {
+        Object::AntClass(AntClass { name, variants: Some(variants), .. }) => (name, variants),
+        it => return Err(format!("expected an enum, got: {}", it.inspect())),
+    };
+
+    let expected = match variants.iter().find(|it| &*it.name == name) {
+        Some(it) => it,
+        None => return Err(format!("enum {enum_name} has no variant '{name}'")),
+    };
+
+    Ok(matches!(
+        value,
+        Object::AntEnumValue(value)
+            if value.variant.tag == expected.tag && value.variant.enum_name == expected.enum_name
+    ))
+}
+
+pub fn match_has_key(value: &Object, key: &Object) -> bool {
+    match (value, key) {
+        (Object::AntHashMap(map), key) => map.map.contains_key(key),
+        (Object::AntClass(clazz), Object::AntString(field)) => {
+            clazz.get_member(&field.value).is_some() && clazz.private_owner(&field.value).is_none()
+        }
+        (Object::AntEnumValue(value), Object::AntString(field)) => {
+            value.get_field(&field.value).is_some()
+        }
+        _ => false,
+    }
+}
@@ -66,7 +66,7 @@ pub fn eval_obj_member(vm: &mut Vm, obj: Rc<RefCell<Object>>, field: String) ->
     let o_borrow = obj.borrow();
 
     if let Object::AntClass(clazz) = &*o_borrow {
-        if let Some(__get__) = clazz.map.get("__get__") {
+        if let Some(__get__) = clazz.get_member("__get__") {
             match __get__ {
                 Object::Method(method) => {
                     let mut m = method.clone();
@@ -88,7 +88,7 @@ pub fn eval_obj_member(vm: &mut Vm, obj: Rc<RefCell<Object>>, field: String) ->
             }
         }
 
-        let value = match match clazz.map.get(&field) {
+        let value = match match clazz.get_member(&field) {
             Some(it) => it,
             None => Err(format!(
                 "object '{}' has no field '{}'",
@@ -96,14 +96,12 @@ pub fn eval_obj_member(vm: &mut Vm, obj: Rc<RefCell<Object>>, field: String) ->
                 field
             ))?,
         } {
-            Object::Method(method) => {
-                let mut m = method.clone();
-
-                m.me = Some(obj.clone());
+            Object::Method(mut method) => {
+                method.me = Some(obj.clone());
 
-                Object::Method(m)
+                Object::Method(method)
             }
-            other => other.clone(),
+            other => other,
         };
 
         if let Err(msg) = vm.push(rc_ref_cell!(value)) {
@@ -113,6 +111,42 @@ pub fn eval_obj_member(vm: &mut Vm, obj: Rc<RefCell<Object>>, field: String) ->
         return Ok(());
     }
 
+    if let Object::AntEnumValue(value) = &*o_borrow {
+        let field_value = match value.get_field(&field) {
+            Some(it) => it.clone(),
+            None => Err(format!(
+                "object '{}' has no field '{}'",
+                value.inspect(),
+                field
+            ))?,
+        };
+
+        if let Err(msg) = vm.push(rc_ref_cell!(field_value)) {
+            return Err(format!("error push field: {msg}"));
+        }
+
+        return Ok(());
+    }
+
+    if let Object::AntError(err) = &*o_borrow {
+        let value = match field.as_str() {
+            "name" => err.error_name.clone(),
+            "message" => err.message.clone(),
+            "traceback" => err.traceback.clone(),
+            _ => Err(format!(
+                "object '{}' has no field '{}'",
+                err.inspect(),
+                field
+            ))?,
+        };
+
+        if let Err(msg) = vm.push(rc_ref_cell!(Object::AntString(AntString::new(value)))) {
+            return Err(format!("error push field: {msg}"));
+        }
+
+        return Ok(());
+    }
+
     if let Some(m) = BUILTIN_TYPE_MAP.get(&o_borrow.get_type()) {
         return eval_native_obj_member(vm, obj.clone(), m, field);
     }
@@ -1,7 +1,7 @@
 use bigdecimal::BigDecimal;
 
 use crate::{
-    byte_code_vm::{code::code::{OpCode, OP_BANG, OP_MINUS}, utils::native_boolean_to_object}, obj_enum::object::Object, object::{ant_double::AntDouble, ant_int::AntInt}
+    byte_code_vm::{code::code::{OpCode, OP_BANG, OP_BIT_NOT, OP_MINUS}, utils::native_boolean_to_object}, obj_enum::object::Object, object::{ant_double::AntDouble, ant_i64::AntI64, ant_int::AntInt}
 };
 
 pub fn bang(right: Object) -> Result<Object, String> {
@@ -21,6 +21,10 @@ pub fn minus(right: Object) -> Result<Object, String> {
     match right {
         Object::AntBoolean(right) => Ok(Object::AntInt(AntInt::from(-(right.value as i32)))),
         Object::AntInt(right) => Ok(Object::AntInt(AntInt::from(-&right.value))),
+        Object::AntI64(right) => match right.value.checked_neg() {
+            Some(it) => Ok(Object::AntI64(AntI64::from(it))),
+            None => Err("integer overflow".to_string()),
+        },
         Object::AntDouble(right) => Ok(Object::AntDouble(AntDouble::from(-&right.value))),
 
         _ => Err(format!(
@@ -30,10 +34,24 @@ pub fn minus(right: Object) -> Result<Object, String> {
     }
 }
 
+pub fn bit_not(right: Object) -> Result<Object, String> {
+    match right {
+        Object::AntI64(right) => Ok(Object::AntI64(AntI64::from(!right.value))),
+        // -x - 1, 即无限位宽补码下的按位取反
+        Object::AntInt(right) => Ok(Object::AntInt(AntInt::from(-&right.value - 1))),
+
+        _ => Err(format!(
+            "unimplemented for type: {:?}",
+            right
+        ))
+    }
+}
+
 pub fn eval_prefix_operator(op: OpCode, right: Object) -> Result<Object, String> {
     match op {
         OP_BANG => bang(right),
         OP_MINUS => minus(right),
+        OP_BIT_NOT => bit_not(right),
 
         _ => Err(format!("unknown prefix operator: {}", op)),
     }
@@ -4,7 +4,7 @@ use bigdecimal::Signed;
 use num_traits::ToPrimitive;
 
 use crate::{
-    big_dec, obj_enum::object::Object, object::object::{IAntObject, ARRAY, HASH_MAP, INT}, try_unwrap_ref
+    big_dec, obj_enum::object::Object, object::object::{IAntObject, ARRAY, HASH_MAP, INT, TUPLE}, try_unwrap_ref
 };
 
 fn eval_set_index_array(
@@ -86,7 +86,12 @@ pub fn eval_set_index(
         return eval_set_index_array(value, index, target);
     } else if target.borrow().get_type() == HASH_MAP {
         return eval_set_value_hash_map(value, index, target);
-    } 
+    } else if target.borrow().get_type() == TUPLE {
+        return Err(format!(
+            "tuple is immutable, cannot set index of: {}",
+            target.borrow().inspect()
+        ));
+    }
 
     Err(format!("cannot set index of object {:?}", target.borrow()))
 }
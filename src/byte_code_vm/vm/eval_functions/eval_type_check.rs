@@ -0,0 +1,35 @@
+use crate::{
+    byte_code_vm::vm::eval_functions::eval_match::match_class,
+    obj_enum::object::Object,
+    object::{
+        object::{ANY, IAntObject},
+        type_hint::TypeHint,
+    },
+};
+
+// 用于报错的类型名, 类 (及其实例) 使用类名, 枚举值使用枚举名
+pub fn type_name(value: &Object) -> String {
+    match value {
+        Object::AntClass(clazz) => clazz.name.to_string(),
+        Object::AntEnumValue(value) => value.variant.enum_name.to_string(),
+        it => it.get_type(),
+    }
+}
+
+// 值的类型与注解中任意一个类型相同 (类可匹配父类名) 即通过
+pub fn match_type_hint(value: &Object, hint: &TypeHint) -> bool {
+    let value_type = value.get_type();
+
+    hint.types
+        .iter()
+        .any(|it| it == ANY || *it == value_type || match_class(value, it))
+}
+
+// target 描述被检查的值, 如 "argument 'n' of f"
+pub fn check_type_hint(value: &Object, hint: &TypeHint, target: impl FnOnce() -> String) -> Result<(), String> {
+    if match_type_hint(value, hint) {
+        return Ok(());
+    }
+
+    Err(format!("{} expected {hint}, got {}", target(), type_name(value)))
+}
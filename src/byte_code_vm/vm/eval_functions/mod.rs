@@ -5,4 +5,10 @@ pub mod eval_infix_operator;
 pub mod eval_prefix_operator;
 pub mod eval_set_index;
 pub mod eval_hash_literal_utils;
-pub mod eval_class_utils;
\ No newline at end of file
+pub mod eval_class_utils;
+pub mod eval_iter;
+pub mod eval_format;
+pub mod eval_match;
+pub mod eval_destructure;
+pub mod eval_type_check;
+pub mod eval_dunder;
\ No newline at end of file
@@ -5,11 +5,18 @@ use crate::{
     object::{ant_closure::Closure, ant_compiled_function::CompiledFunction},
 };
 
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct ExceptionHandler {
+    pub catch_ip: usize,
+    pub sp: usize,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq)]
 pub struct Frame {
     pub closure: Closure,
     pub ip: isize,
     pub base_pointer: usize,
+    pub handlers: Vec<ExceptionHandler>,
 }
 
 impl Frame {
@@ -19,6 +26,7 @@ impl Frame {
             closure,
             ip: -1,
             base_pointer,
+            handlers: vec![],
         }
     }
 
@@ -64,6 +72,7 @@ pub fn fmt_frames(frames: &Vec<Frame>) -> String {
         s.push_str(&format!("    {}\n", fmt_closure(f.closure.clone(), "\t")));
         s.push_str(&format!("    InstructionsPos: {}\n", f.ip));
         s.push_str(&format!("    BasePointer: {}\n", f.base_pointer));
+        s.push_str(&format!("    Handlers: {:?}\n", f.handlers));
     }
 
     s
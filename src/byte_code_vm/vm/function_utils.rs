@@ -1,31 +1,60 @@
 use std::{cell::RefCell, rc::Rc};
 
+use indexmap::IndexMap;
+
 use crate::{
     byte_code_vm::{
         constants::{NONE_OBJ, UNINIT_OBJECT},
-        vm::{frame::Frame, vm::Vm},
+        vm::{
+            eval_functions::{eval_dunder::bind_dunder, eval_type_check::check_type_hint},
+            frame::Frame,
+            generator_utils::create_generator,
+            vm::Vm,
+        },
     },
     obj_enum::object::Object,
-    object::{ant_closure::Closure, ant_method::MethodType},
+    object::{
+        ant_array::AntArray, ant_closure::{Closure, Upvalue},
+        ant_compiled_function::{Capture, CompiledFunction},
+        ant_hash_map::AntHashMap, ant_method::MethodType, ant_string::AntString,
+    },
     rc_ref_cell,
 };
 
-pub fn call(vm: &mut Vm, arg_count: usize) -> Result<(), String> {
-    let top = vm.sp - 1 - arg_count;
+// kw_names 为关键字实参名, 对应的值位于位置实参之后
+pub fn call(vm: &mut Vm, arg_count: usize, kw_names: &[String]) -> Result<(), String> {
+    let top = vm.sp - 1 - arg_count - kw_names.len();
     let obj_tag = {
         let borrow = vm.stack[top].borrow();
         match &*borrow {
             Object::Closure(_) => 0u8,
             Object::AntNativeFunction(_) => 1u8,
             Object::Method(_) => 2u8,
+            Object::AntClass(_) => 3u8,
             it => return Err(format!("calling non-function: {it:#?}")),
         }
     };
 
+    // 可调用对象: 调用其 __call__ 方法
+    if obj_tag == 3 {
+        let method = match bind_dunder(&vm.stack[top], "__call__") {
+            Some(it) => it,
+            None => return Err(format!("calling non-function: {:#?}", vm.stack[top].borrow())),
+        };
+
+        vm.stack[top] = rc_ref_cell!(Object::Method(method));
+
+        return call_method(vm, vm.stack[top].clone(), arg_count, kw_names);
+    }
+
+    if obj_tag == 1 && !kw_names.is_empty() {
+        return Err(String::from("native function does not accept keyword arguments"));
+    }
+
     match obj_tag {
-        0 => call_closure(vm, vm.stack[top].clone(), arg_count),
+        0 => call_closure(vm, vm.stack[top].clone(), arg_count, kw_names),
         1 => call_native(vm, vm.stack[top].clone(), arg_count),
-        2 => call_method(vm, vm.stack[top].clone(), arg_count),
+        2 => call_method(vm, vm.stack[top].clone(), arg_count, kw_names),
         _ => Err(format!("calling non-function")),
     }
 }
@@ -40,8 +69,24 @@ pub fn call_native(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) -> R
     };
 
     let args = &vm.stack[vm.sp - arg_count..vm.sp];
+
+    // 原生函数的类型注解以实参下标 ("0", "1", ...) 与 "return" 为键
+    if let Some(hints) = &calling_obj.type_hint_map {
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(hint) = hints.get_hint(&i.to_string()) {
+                check_type_hint(&arg.borrow(), hint, || format!("argument {i} of native function"))?;
+            }
+        }
+    }
+
     let result = (calling_obj.function)(vm, args.to_vec());
 
+    if let (Some(hints), Ok(Some(value))) = (&calling_obj.type_hint_map, &result)
+        && let Some(hint) = hints.get_hint("return")
+    {
+        check_type_hint(value, hint, || String::from("return value of native function"))?;
+    }
+
     // 调整栈指针以移除 函数对象 + 参数
     let base_pointer_of_function = vm.sp - arg_count - 1;
     vm.sp = base_pointer_of_function;
@@ -62,7 +107,109 @@ pub fn call_native(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) -> R
     Ok(())
 }
 
-pub fn call_closure(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) -> Result<(), String> {
+// 按形参信息绑定实参: 多余的位置实参放入 *rest, 未知的关键字实参放入 **opts,
+// 未传入且有默认值的形参置为 Uninit, 由函数开头的 OpJumpIfBound 计算默认值
+fn bind_args(
+    vm: &mut Vm,
+    func: &CompiledFunction,
+    base_pointer: usize,
+    arg_count: usize,
+    kw_names: &[String],
+) -> Result<(), String> {
+    let params = &func.params;
+
+    let args = vm.stack[base_pointer..base_pointer + arg_count + kw_names.len()].to_vec();
+    let (positional, keyword_values) = args.split_at(arg_count);
+
+    if positional.len() > params.names.len() && !params.rest {
+        return Err(format!(
+            "expected at most {} args, got {arg_count} args",
+            params.names.len()
+        ));
+    }
+
+    let mut slots = vec![None; params.names.len()];
+
+    for (slot, arg) in slots.iter_mut().zip(positional) {
+        *slot = Some(arg.clone());
+    }
+
+    let mut keywords = IndexMap::new();
+
+    for (name, value) in kw_names.iter().zip(keyword_values) {
+        match params.names.iter().position(|it| **it == **name) {
+            Some(i) if slots[i].is_some() => {
+                return Err(format!("got multiple values for argument '{name}'"));
+            }
+            Some(i) => slots[i] = Some(value.clone()),
+            None if params.keywords => {
+                keywords.insert(
+                    Object::AntString(AntString::new(name.clone())),
+                    value.borrow().clone(),
+                );
+            }
+            None => return Err(format!("unexpected keyword argument '{name}'")),
+        }
+    }
+
+    for (i, slot) in slots.into_iter().enumerate() {
+        vm.stack[base_pointer + i] = match slot {
+            Some(it) => it,
+            None if i < params.required => {
+                return Err(format!("missing argument '{}'", params.names[i]));
+            }
+            None => rc_ref_cell!(UNINIT_OBJECT.clone()),
+        };
+    }
+
+    let mut index = base_pointer + params.names.len();
+
+    if params.rest {
+        let rest = positional
+            .iter()
+            .skip(params.names.len())
+            .map(|it| it.borrow().clone())
+            .collect::<Vec<_>>();
+
+        vm.stack[index] = rc_ref_cell!(Object::AntArray(AntArray::from(rest)));
+        index += 1;
+    }
+
+    if params.keywords {
+        vm.stack[index] = rc_ref_cell!(Object::AntHashMap(AntHashMap::from(keywords)));
+    }
+
+    Ok(())
+}
+
+// 按类型注解检查已绑定的实参, 未传入 (使用默认值) 的形参不检查
+fn check_arg_types(vm: &Vm, func: &CompiledFunction, base_pointer: usize) -> Result<(), String> {
+    for (i, hint) in func.params.type_hints.iter().enumerate() {
+        let Some(hint) = hint else { continue };
+
+        let arg = vm.stack[base_pointer + i].borrow();
+
+        if matches!(&*arg, Object::AntUninit(_)) {
+            continue;
+        }
+
+        check_type_hint(&arg, hint, || {
+            format!(
+                "argument '{}' of {}",
+                func.params.names[i], func.scope_info.scope_name
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn call_closure(
+    vm: &mut Vm,
+    obj: Rc<RefCell<Object>>,
+    arg_count: usize,
+    kw_names: &[String],
+) -> Result<(), String> {
     let obj_borrow = obj.borrow();
 
     let calling_obj = if let Object::Closure(it) = &*obj_borrow {
@@ -73,16 +220,27 @@ pub fn call_closure(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) ->
 
     let func = &calling_obj.func;
 
-    if arg_count != func.param_count {
-        return Err(format!(
-            "expected {} args, got {arg_count} args",
-            func.param_count
-        ));
+    let base_pointer = vm.sp - arg_count - kw_names.len();
+
+    if func.params.is_positional() && kw_names.is_empty() {
+        if arg_count != func.param_count {
+            return Err(format!(
+                "expected {} args, got {arg_count} args",
+                func.param_count
+            ));
+        }
+    } else {
+        bind_args(vm, func, base_pointer, arg_count, kw_names)?;
+    }
+
+    if !func.unchecked {
+        check_arg_types(vm, func, base_pointer)?;
     }
 
     let local_count = func.local_count;
+    let is_generator = func.is_generator;
 
-    let frame = Frame::new(calling_obj, vm.sp - arg_count);
+    let frame = Frame::new(calling_obj, base_pointer);
 
     let frame_base_pointer = frame.base_pointer;
 
@@ -90,10 +248,20 @@ pub fn call_closure(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) ->
 
     vm.sp = frame_base_pointer + local_count;
 
+    // 生成器函数不立即执行, 而是返回保存了该栈帧的生成器
+    if is_generator {
+        return create_generator(vm);
+    }
+
     Ok(())
 }
 
-pub fn call_method(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) -> Result<(), String> {
+pub fn call_method(
+    vm: &mut Vm,
+    obj: Rc<RefCell<Object>>,
+    arg_count: usize,
+    kw_names: &[String],
+) -> Result<(), String> {
     let obj_borrow = obj.borrow();
 
     let calling_obj = if let Object::Method(it) = &*obj_borrow {
@@ -106,53 +274,73 @@ pub fn call_method(vm: &mut Vm, obj: Rc<RefCell<Object>>, arg_count: usize) -> R
 
     // 将方法对象替换为 self 对象
     if let Some(o) = calling_obj.me {
-        vm.stack.insert(vm.sp - arg_count, o);
+        vm.stack.insert(vm.sp - arg_count - kw_names.len(), o);
         vm.sp += 1;
         arg_count += 1
     }
 
     match calling_obj.func {
-        MethodType::Closure(cl) => call_closure(vm, rc_ref_cell!(Object::Closure(cl)), arg_count),
+        MethodType::Closure(cl) => {
+            call_closure(vm, rc_ref_cell!(Object::Closure(cl)), arg_count, kw_names)
+        }
+        MethodType::NativeFunction(_) if !kw_names.is_empty() => {
+            Err(String::from("native function does not accept keyword arguments"))
+        }
         MethodType::NativeFunction(f) => {
             call_native(vm, rc_ref_cell!(Object::AntNativeFunction(f)), arg_count)
         }
     }
 }
 
-pub fn push_closure(vm: &mut Vm, const_index: u16, free_count: u16) -> Result<(), String> {
-    let constant = &vm.constants[const_index as usize];
-    let constant_borrow = constant.borrow();
-
-    let func = match &*constant_borrow {
-        Object::CompiledFunction(f) => f,
-        _ => return Err(format!("not a function: {:?}", constant)),
-    };
-
-    let free_count_usize = free_count as usize;
-
-    if free_count_usize == 0 {
-        let closure = Closure {
-            func: func.clone(),
-            free: rc_ref_cell!(vec![]),
-        };
+// 查找或创建指向栈位置 index 的 upvalue, 使捕获同一变量的闭包共享它
+fn capture_local(vm: &mut Vm, index: usize) -> Rc<RefCell<Upvalue>> {
+    let found = vm
+        .open_upvalues
+        .iter()
+        .find(|it| matches!(&*it.borrow(), Upvalue::Open(it) if *it == index));
 
-        drop(constant_borrow);
-        return vm.push(rc_ref_cell!(Object::Closure(closure)));
+    if let Some(it) = found {
+        return it.clone();
     }
 
-    let mut free = vec![UNINIT_OBJECT.clone(); free_count_usize];
+    let upvalue = rc_ref_cell!(Upvalue::Open(index));
+    vm.open_upvalues.push(upvalue.clone());
 
-    for i in 0..free_count_usize {
-        free[i] = vm.stack[vm.sp - free_count_usize + i].borrow().clone();
-    }
-
-    vm.sp -= free_count_usize;
+    upvalue
+}
 
-    let closure = Closure {
-        func: func.clone(),
-        free: rc_ref_cell!(free),
+pub fn push_closure(vm: &mut Vm, const_index: u16, free_count: u16) -> Result<(), String> {
+    let func = match &*vm.constants[const_index as usize].borrow() {
+        Object::CompiledFunction(f) => f.clone(),
+        it => return Err(format!("not a function: {:?}", it)),
     };
 
-    drop(constant_borrow);
-    vm.push(rc_ref_cell!(Object::Closure(closure)))
-}
\ No newline at end of file
+    if func.captures.len() != free_count as usize {
+        return Err(format!(
+            "expected {} free variables, got {}",
+            func.captures.len(),
+            free_count
+        ));
+    }
+
+    let frame = vm.current_frame();
+    let base_pointer = frame.base_pointer;
+    let enclosing = frame.closure.clone();
+
+    let free = func
+        .captures
+        .iter()
+        .map(|capture| match capture {
+            Capture::Local(index) => capture_local(vm, base_pointer + index),
+            Capture::Free(index) => enclosing.free[*index].clone(),
+            Capture::CurrentClosure => rc_ref_cell!(Upvalue::Closed(rc_ref_cell!(
+                Object::Closure(enclosing.clone())
+            ))),
+        })
+        .collect();
+
+    vm.push(rc_ref_cell!(Object::Closure(Closure {
+        func,
+        free: Rc::new(free),
+    })))
+}
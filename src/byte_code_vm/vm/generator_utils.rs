@@ -0,0 +1,172 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    byte_code_vm::vm::vm::Vm,
+    obj_enum::object::Object,
+    object::{
+        ant_closure::Upvalue,
+        ant_generator::{AntGenerator, GeneratorState},
+    },
+    rc_ref_cell,
+};
+
+// 调用生成器函数: 实参已绑定到新栈帧中, 将该栈帧连同局部变量保存到生成器后弹出
+pub fn create_generator(vm: &mut Vm) -> Result<(), String> {
+    let frame = vm.pop_frame().clone();
+    let base_pointer = frame.base_pointer;
+
+    let stack = vm.stack[base_pointer..vm.sp].to_vec();
+
+    vm.sp = base_pointer - 1;
+
+    vm.push(rc_ref_cell!(Object::AntGenerator(AntGenerator::new(frame, stack))))
+}
+
+// OpYield: 挂起当前栈帧, 被 yield 的值留在栈顶交给 resume_generator
+// 挂起时被闭包捕获的局部变量会被关闭, 恢复时重新指向栈上的局部变量
+pub fn suspend_generator(vm: &mut Vm) -> Result<(), String> {
+    let value = match vm.pop() {
+        Some(it) => it,
+        None => return Err(String::from("expected a value to yield")),
+    };
+
+    let base_pointer = vm.current_frame().base_pointer;
+
+    let state = match &*vm.stack[base_pointer - 1].borrow() {
+        Object::AntGenerator(it) => it.state.clone(),
+        _ => return Err(String::from("cannot yield outside generator")),
+    };
+
+    // 记录指向生成器栈帧的 upvalue, pop_frame 会将其关闭
+    let upvalues = vm
+        .open_upvalues
+        .iter()
+        .filter_map(|upvalue| match &*upvalue.borrow() {
+            Upvalue::Open(index) if *index >= base_pointer => {
+                Some((upvalue.clone(), index - base_pointer))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut frame = vm.pop_frame().clone();
+
+    frame.handlers.iter_mut().for_each(|it| it.sp -= base_pointer);
+
+    {
+        let mut state = state.borrow_mut();
+
+        state.stack = vm.stack[base_pointer..vm.sp].to_vec();
+        state.upvalues = upvalues;
+        state.frame = Some(frame);
+    }
+
+    vm.sp = base_pointer - 1;
+
+    vm.push(value)
+}
+
+/// 恢复生成器直至下一次 yield
+/// 返回 None 表示生成器已结束
+pub fn resume_generator(
+    vm: &mut Vm,
+    generator: Rc<RefCell<Object>>,
+) -> Result<Option<Rc<RefCell<Object>>>, String> {
+    let state = match &*generator.borrow() {
+        Object::AntGenerator(it) => it.state.clone(),
+        it => return Err(format!("expected a generator, got: {it:?}")),
+    };
+
+    let (mut frame, stack, upvalues) = {
+        let mut state = state.borrow_mut();
+
+        if state.running {
+            return Err(String::from("generator is already running"));
+        }
+
+        match state.frame.take() {
+            Some(frame) => (
+                frame,
+                std::mem::take(&mut state.stack),
+                std::mem::take(&mut state.upvalues),
+            ),
+            None => return Ok(None),
+        }
+    };
+
+    // 生成器对象占据被调用者的位置, OpYield 通过它找到需要保存的状态
+    vm.push(generator)?;
+
+    let base_pointer = vm.sp;
+
+    for value in stack {
+        vm.push(value)?;
+    }
+
+    // 挂起期间闭包可能修改了被关闭的值, 将其写回栈上并重新打开
+    for (upvalue, offset) in upvalues {
+        let index = base_pointer + offset;
+
+        if let Upvalue::Closed(value) = &*upvalue.borrow() {
+            vm.stack[index] = value.clone();
+        }
+
+        *upvalue.borrow_mut() = Upvalue::Open(index);
+        vm.open_upvalues.push(upvalue);
+    }
+
+    frame.base_pointer = base_pointer;
+    frame.handlers.iter_mut().for_each(|it| it.sp += base_pointer);
+
+    state.borrow_mut().running = true;
+
+    vm.push_frame(frame);
+
+    let result = run_generator_frame(vm, base_pointer, &state);
+
+    state.borrow_mut().running = false;
+
+    result
+}
+
+fn run_generator_frame(
+    vm: &mut Vm,
+    base_pointer: usize,
+    state: &RefCell<GeneratorState>,
+) -> Result<Option<Rc<RefCell<Object>>>, String> {
+    // 只处理生成器栈帧及其调用产生的栈帧中的异常处理器
+    let min_frame_index = vm.frame_index - 1;
+
+    loop {
+        if vm.frame_index == min_frame_index {
+            // 栈帧已被 OpYield 挂起
+            if state.borrow().frame.is_some() {
+                return Ok(vm.pop());
+            }
+
+            // 生成器函数返回, 生成器结束, 保存返回值
+            state.borrow_mut().return_value = vm.pop();
+
+            return Ok(None);
+        }
+
+        vm.current_frame().ip += 1;
+
+        let ip = vm.current_frame().ip as usize;
+
+        let instructions = vm.current_frame().instructions();
+
+        let op = instructions[ip];
+
+        if let Err(msg) = vm.next(op, ip, instructions)
+            && let Err(msg) = vm.handle_error(msg, min_frame_index)
+        {
+            // 未被捕获的错误同样结束生成器
+            vm.frame_index = min_frame_index + 1;
+            vm.pop_frame();
+            vm.sp = base_pointer - 1;
+
+            return Err(msg);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod eval_functions;
 pub mod frame;
 pub mod function_utils;
+pub mod generator_utils;
 pub mod test;
 pub mod vm;
@@ -52,9 +52,66 @@ mod tests {
             VmTestCase::<BigDecimal>::new("-50 + 100 + -50".into(), big_dec!(0)),
             VmTestCase::<BigDecimal>::new("(5 + 10 * 2 + 15 / 3) * 2 + -10".into(), big_dec!(50)),
             VmTestCase::<BigDecimal>::new("5 * (2 + 10)".into(), big_dec!(60)),
+            VmTestCase::<BigDecimal>::new("7 % 3".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new("-7 % 3".into(), big_dec!(2)),
+            VmTestCase::<BigDecimal>::new("7 % -3".into(), big_dec!(-2)),
+            VmTestCase::<BigDecimal>::new("7 ~/ 2".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("-7 ~/ 2".into(), big_dec!(-4)),
+            VmTestCase::<BigDecimal>::new("2 ** 10".into(), big_dec!(1024)),
+            VmTestCase::<BigDecimal>::new("2 ** 3 ** 2".into(), big_dec!(512)),
+            VmTestCase::<BigDecimal>::new("-2 ** 2".into(), big_dec!(-4)),
+            VmTestCase::<BigDecimal>::new("2 * 3 ** 2 % 5".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("2 ** -1".into(), big_dec_from_str!(0.5)),
+            VmTestCase::<BigDecimal>::new("7.5 % 2".into(), big_dec_from_str!(1.5)),
+            VmTestCase::<BigDecimal>::new("-7.5 ~/ 2".into(), big_dec!(-4)),
         ];
 
-        run_vm_tests::<BigDecimal>(tests)
+        run_vm_tests::<BigDecimal>(tests);
+
+        run_vm_error_tests(
+            "",
+            &[
+                ("1 % 0", "modulo by zero"),
+                ("1 ~/ 0", "division by zero"),
+                ("2i64 ** 64i64", "integer overflow"),
+                ("2 ** 10 ** 9", "result too large"),
+                ("1 << 10 ** 9", "shift count too large"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("6 & 3".into(), big_dec!(2)),
+            VmTestCase::<BigDecimal>::new("6 | 3".into(), big_dec!(7)),
+            VmTestCase::<BigDecimal>::new("6 ^ 3".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new("~5".into(), big_dec!(-6)),
+            VmTestCase::<BigDecimal>::new("-6 & 3".into(), big_dec!(2)),
+            VmTestCase::<BigDecimal>::new("1 << 70 >> 68".into(), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new("-9 >> 1".into(), big_dec!(-5)),
+            VmTestCase::<BigDecimal>::new("1 | 2 ^ 3 & 4".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("1 + 1 << 2".into(), big_dec!(8)),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::new("str(6i64 & 3i64)".into(), String::from("2")),
+            VmTestCase::new("str(~5i64)".into(), String::from("-6")),
+            VmTestCase::new("str(1i64 << 63i64)".into(), String::from("-9223372036854775808")),
+            VmTestCase::new("str(-9i64 >> 1i64)".into(), String::from("-5")),
+        ];
+
+        run_vm_tests(tests2);
+
+        run_vm_error_tests(
+            "",
+            &[
+                ("1i64 << 64i64", "shift count out of range (0..64): 64"),
+                ("1 >> -1", "negative shift count"),
+            ],
+        );
     }
 
     #[test]
@@ -85,9 +142,304 @@ mod tests {
             VmTestCase::<bool>::new("!!true".into(), true),
             VmTestCase::<bool>::new("!!false".into(), false),
             VmTestCase::<bool>::new("!!5".into(), true),
+            VmTestCase::<bool>::new("1 <= 1".into(), true),
+            VmTestCase::<bool>::new("2 <= 1".into(), false),
+            VmTestCase::<bool>::new("1 >= 1".into(), true),
+            VmTestCase::<bool>::new("1 >= 2".into(), false),
+            VmTestCase::<bool>::new("1.5 < 2".into(), true),
+            VmTestCase::<bool>::new("2i64 >= 3i64".into(), false),
+        ];
+
+        run_vm_tests(tests);
+
+        // 比较运算的操作数从左到右求值
+        let tests2 = vec![VmTestCase::new(
+            r#"
+            let s = "";
+            func f() { s = s + "f"; 1 }
+            func g() { s = s + "g"; 2 }
+            f() < g();
+            g() >= f();
+            s
+            "#
+            .into(),
+            String::from("fggf"),
+        )];
+
+        run_vm_tests(tests2);
+
+        // 不能比较大小的类型, <= 与 >= 同样报错
+        run_vm_error_tests(
+            "",
+            &[
+                ("[1] <= [1]", "unimplemented for types: Array and Array"),
+                ("none >= none", "unimplemented for types: None and None"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_logical_operators() {
+        let tests = vec![
+            VmTestCase::<bool>::new("true and true".into(), true),
+            VmTestCase::<bool>::new("true and false".into(), false),
+            VmTestCase::<bool>::new("false or true".into(), true),
+            VmTestCase::<bool>::new("false or false".into(), false),
+            VmTestCase::<bool>::new("1 < 2 and 2 < 3".into(), true),
+        ];
+
+        run_vm_tests(tests);
+
+        // 返回决定结果的操作数
+        let tests2 = vec![
+            VmTestCase::<BigDecimal>::new("0 or 5".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new("3 or 5".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("3 and 5".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new("0 and 5".into(), big_dec!(0)),
+        ];
+
+        run_vm_tests(tests2);
+
+        let tests3 = vec![
+            VmTestCase::new(r#"let name = ""; name or "default""#.into(), String::from("default")),
+            VmTestCase::new(
+                r#"
+                let s = "";
+                func f(v) { s = s + str(v); v }
+                f(0) and f(1);
+                f(2) or f(3);
+                f(0) or f(4);
+                s
+                "#
+                .into(),
+                String::from("0204"),
+            ),
+            VmTestCase::new(
+                r#"
+                let x = none;
+                if x != none and x.f() { "called" } else { "skipped" }
+                "#
+                .into(),
+                String::from("skipped"),
+            ),
+        ];
+
+        run_vm_tests(tests3);
+
+        // none 只与 none 相等, 供 `x != none and ...` 这类判空使用
+        let tests4 = vec![
+            VmTestCase::<bool>::new("none == none".into(), true),
+            VmTestCase::<bool>::new("none != none".into(), false),
+            VmTestCase::<bool>::new("let x = none; x == none".into(), true),
+            VmTestCase::<bool>::new("1 == none".into(), false),
+            VmTestCase::<bool>::new("none != 0".into(), true),
+        ];
+
+        run_vm_tests(tests4);
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("let a = 1; a += 2; a".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("let a = 10; a -= 2; a *= 3; a ~/= 5; a".into(), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new("let a = 3; a **= 2; a %= 5; a".into(), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new("let a = 1; a <<= 4; a |= 3; a ^= 1; a &= 6; a >>= 1; a".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new("func f() { let a = 1; a += 1; a }; f()".into(), big_dec!(2)),
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                class A { let x = 1; }
+                let a = copy(A);
+                a.x += 5;
+                A::x *= 3;
+                a.x + A::x
+                "#
+                .into(),
+                big_dec!(9),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        // 容器与索引只求值一次
+        let tests2 = vec![VmTestCase::new(
+            r#"
+            let arr = [1, 2, 3];
+            let n = 0;
+            func f() { n += 1; 1 }
+            arr[f()] += 10;
+            arr + [n]
+            "#
+            .into(),
+            vec![big_dec!(1), big_dec!(12), big_dec!(3), big_dec!(1)],
+        )];
+
+        run_vm_tests(tests2);
+    }
+
+    #[test]
+    fn test_format_string() {
+        let tests = vec![
+            VmTestCase::new(r#"let x = 41; f"value = {x + 1}""#.into(), String::from("value = 42")),
+            VmTestCase::new(r#"f"{3.14159:.2f}|{5:>4}|{255:x}|{-7:05}|{3:+}""#.into(), String::from("3.14|   5|ff|-0007|+3")),
+            VmTestCase::new(r#"f"{"ab" + "c":*^7}|{"hello":.3}|{{}}""#.into(), String::from("**abc**|hel|{}")),
+            VmTestCase::new(r#"let h = {"a": [1, 2]}; f"{h["a"]} {str(1.50)}""#.into(), String::from("[1, 2] 1.5")),
+            VmTestCase::new(r#"f"""#.into(), String::new()),
+        ];
+
+        run_vm_tests(tests);
+
+        run_vm_error_tests("", &[(r#"f"{"s":d}""#, "unsupported format specifier 'd' for type: String")]);
+    }
+
+    #[test]
+    fn test_match_expression() {
+        let describe = r#"
+            class P { let x = 0; }
+            class Q : P { let y = 1; }
+
+            func describe(v) {
+                match v {
+                    0 => "zero",
+                    -1 => "minus one",
+                    "hi" => "greeting",
+                    none => "nothing",
+                    [] => "empty",
+                    [1, [a, _]] => "nested " + str(a),
+                    [a, b] if a > b => "desc",
+                    [a, b] => { let s = a + b; "sum " + str(s) },
+                    {"k": 1, "v": val} => "kv " + str(val),
+                    P { x: 3, y } => "p " + str(y),
+                    Option { is_null: false, value } => "some " + str(value),
+                    n if n > 100 => "big",
+                    _ => "other"
+                }
+            }
+        "#;
+
+        let cases = [
+            ("0", "zero"),
+            ("-1", "minus one"),
+            (r#""hi""#, "greeting"),
+            ("none", "nothing"),
+            ("[]", "empty"),
+            ("[1, [7, 8]]", "nested 7"),
+            ("[3, 2]", "desc"),
+            ("[2, 3]", "sum 5"),
+            (r#"{"k": 1, "v": "z"}"#, "kv z"),
+            ("func () { let q = copy(Q); q.x = 3; q }()", "p 1"),
+            ("Some(3)", "some 3"),
+            ("1000", "big"),
+            ("42", "other"),
+        ];
+
+        let tests = cases
+            .iter()
+            .map(|(arg, expected)| {
+                VmTestCase::new(format!("{describe} describe({arg})"), String::from(*expected))
+            })
+            .collect::<Vec<_>>();
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::new(
+                "let total = 0; for i in [0, 1, 2, 3] { match i { 0 => {} n if n % 2 == 0 => { total = total + n } _ => { total = total + 10 } } } total".into(),
+                big_dec!(22),
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        run_vm_error_tests(
+            "",
+            &[("match [1] { [a, b] => a }", "non-exhaustive match: no pattern matched value: [1]")],
+        );
+
+        // 模式绑定只在分支内可见; 分支中的 break / continue 不遗留被匹配值
+        let tests4 = vec![
+            VmTestCase::<BigDecimal>::new("let a = 1; let b = match [5] { [a] => a * 2 }; a * 100 + b".into(), big_dec!(110)),
+            VmTestCase::<BigDecimal>::new("func f() { let a = 1; match 7 { a => a }; a } f()".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new(
+                "let i = 0; let odd = 0; while i < 5000 { i = i + 1; match i % 2 { 0 => { continue } _ => { odd = odd + 1 } } } odd".into(),
+                big_dec!(2500),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                "let n = 0; for i in [1, 2, 3] { while true { match n { x if x >= i => { break } _ => { n = n + 1 } } } } n".into(),
+                big_dec!(3),
+            ),
+        ];
+
+        run_vm_tests(tests4);
+
+        assert!(compile_it("match 1 { x => x }; x".into(), "__test_match__".into()).is_err());
+    }
+
+    #[test]
+    fn test_destructuring_let() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("let [a, b] = [1, 2]; a * 10 + b".into(), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new("let [a, [b, _], ...rest] = [1, [2, 3], 4, 5]; a + b + len(rest)".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new(r#"let {x, y: z} = {"x": 1, "y": 2}; x * 10 + z"#.into(), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new(
+                "class P { let x = 1; let y = 2; } let p = copy(P); p.x = 5; let P {x, y} = p; x + y".into(),
+                big_dec!(7),
+            ),
+            VmTestCase::<BigDecimal>::new("func f(arr) { let [first, ...others] = arr; first + len(others) } f([10, 0, 0])".into(), big_dec!(12)),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![VmTestCase::new("let [a, ...rest] = [1, 2, 3]; rest".into(), vec![big_dec!(2), big_dec!(3)])];
+
+        run_vm_tests(tests2);
+
+        run_vm_error_tests(
+            "",
+            &[
+                ("let [a, b] = [1, 2, 3];", "expected 2 items to destructure, got 3"),
+                ("let [a, b, ...c] = [1];", "expected at least 2 items to destructure, got 1"),
+                ("let [a] = 1;", "cannot destructure Int as an array"),
+                ("let {a} = [1];", "cannot destructure Array as a hash map"),
+                (r#"let {a} = {"b": 1};"#, "missing key \"a\" to destructure"),
+                ("class P {} class Q {} let Q {x} = copy(P);", "cannot destructure class P {} as an instance of 'Q'"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tuple() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("let t = (1, 2, 3); t[0] + t[-1] + len(t)".into(), big_dec!(7)),
+            VmTestCase::<BigDecimal>::new("let m = {(1, 2): 5}; m[(1, 2)]".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new("func f() { return 7, 2 } let [a, b] = f(); a * 10 + b".into(), big_dec!(72)),
+            VmTestCase::<BigDecimal>::new("let s = 0; for x in (1, 2, 3) { s += x } s".into(), big_dec!(6)),
+            VmTestCase::<BigDecimal>::new("(1 + 2) * 3".into(), big_dec!(9)),
+            // 含浮点数, none, 数组等元素的元组按值作为字典键
+            VmTestCase::<BigDecimal>::new("let m = {}; m[(1.5, 2)] = 1; m[(1.5, 2)]".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new("let m = {}; m[(none, true, [1, 2])] = 3; m[(none, true, [1, 2])]".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("let m = {}; m[(1.50, (2.0, 3))] = 4; m[(1.5, (2.0, 3))]".into(), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new("match (1, 2) { [a, b] => a * 10 + b }".into(), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new("match (1, 2, 3) { [a, b] => 0, [a, _, c] => a + c }".into(), big_dec!(4)),
         ];
 
         run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::new("(1, 2) == (1, 2)".into(), true),
+            VmTestCase::new(r#"(1, "a") == (1, "b")"#.into(), false),
+        ];
+
+        run_vm_tests(tests2);
+
+        let tests3 = vec![
+            VmTestCase::new(r#"let t = (1, "a"); f"{t}""#.into(), String::from(r#"(1, "a")"#)),
+            VmTestCase::new(r#"let t = (); f"{t}""#.into(), String::from("()")),
+        ];
+
+        run_vm_tests(tests3);
+
+        run_vm_error_tests("", &[("let t = (1, 2); t[0] = 3;", "tuple is immutable, cannot set index of: (1, 2)")]);
     }
 
     #[test]
@@ -115,6 +467,25 @@ mod tests {
         ];
 
         run_vm_tests(tests2);
+
+        // bug_of_ant/parser/if.ant: 换行后的 -1 是新的语句, 而不是 (if ...) - 1
+        let tests3 = vec![
+            VmTestCase::new(
+                "func f(n) {\n    if n < 2 {\n        return n\n    }\n\n    -1\n}\nf(4)".into(),
+                big_dec!(-1),
+            ),
+            VmTestCase::new(
+                "func f(n) {\n    if n < 2 {\n        return n\n    }\n\n    -1\n}\nf(1)".into(),
+                big_dec!(1),
+            ),
+            VmTestCase::new("let x = if 1 { 10 } else { 20 } - 1\nx".into(), big_dec!(9)),
+            VmTestCase::new("let x = if 0 { 10 }\nelse { 20 }\nx".into(), big_dec!(20)),
+            // 代码块表达式作为右操作数时同样在换行处结束
+            VmTestCase::new("let x = 1 + if 1 { 2 } else { 3 }\n-1\nx".into(), big_dec!(3)),
+            VmTestCase::new("func f(a) {\n    return a + if a { 1 } else { 2 }\n    -1\n}\nf(1)".into(), big_dec!(2)),
+        ];
+
+        run_vm_tests(tests3);
     }
 
     #[test]
@@ -311,119 +682,1216 @@ mod tests {
     }
 
     #[test]
-    fn test_closures() {
+    fn test_function_params() {
         let tests = vec![
-            // 测试1: 基本闭包
+            VmTestCase::<BigDecimal>::new("func f(a, b = a * 2) { a * 10 + b } f(1)".into(), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new("func f(a, b = 2) { a * 10 + b } f(1, 5)".into(), big_dec!(15)),
+            VmTestCase::<BigDecimal>::new("func f(a, b) { a - b } f(b: 1, a: 10)".into(), big_dec!(9)),
+            VmTestCase::<BigDecimal>::new("let n = 1; func f(v = n) { v } n = 5; f()".into(), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new("func f(a, *rest) { a + len(rest) } f(1, 2, 3)".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new(r#"func f(a, **opts) { a + opts["c"] } f(1, c: 2)"#.into(), big_dec!(3)),
             VmTestCase::<BigDecimal>::new(
-                r#"
-                func new_adder(a, b) {
-                    func inner(c) {
-                        a + b + c
-                    }
+                "class C { #[method] let m = func (self, k = 1) { k }; } let c = copy(C); c.m() * 10 + c.m(k: 4)".into(),
+                big_dec!(14),
+            ),
+        ];
 
-                    inner
-                }
+        run_vm_tests(tests);
 
-                let adder = new_adder(1, 2);
-                adder(8);
-                "#
-                .into(),
-                big_dec!(11),
-            ),
-            // 测试2: 带局部变量的闭包
-            VmTestCase::<BigDecimal>::new(
-                r#"
-                func new_adder(a, b) { 
-                    let c = a + b; 
-                    func inner(d) { c + d }; 
+        let tests2 = vec![VmTestCase::new("func f(*rest) { rest } f(1, 2)".into(), vec![big_dec!(1), big_dec!(2)])];
 
-                    inner
-                }; 
-                let adder = new_adder(1, 2); 
-                adder(8); 
-                "#
-                .into(),
-                big_dec!(11),
-            ),
-            // 测试1: 多层闭包
-            VmTestCase::<BigDecimal>::new(
-                r#"
-                func new_adder_outer(a, b) { 
-                    let c = a + b; 
-                    func inner1(d) { 
-                        let e = d + c; 
-                        func inner2(f) { e + f; }; 
+        run_vm_tests(tests2);
 
-                        inner2
-                    }; 
+        let error_cases = [
+            ("func f(a, b = 1) {} f();", "missing argument 'a'"),
+            ("func f(a) {} f(1, 2);", "expected 1 args, got 2 args"),
+            ("func f(a, b = 1) {} f(1, 2, 3);", "expected at most 2 args, got 3 args"),
+            ("func f(a) {} f(1, a: 2);", "got multiple values for argument 'a'"),
+            ("func f(a) {} f(1, z: 2);", "unexpected keyword argument 'z'"),
+            ("len(x: 1);", "native function does not accept keyword arguments"),
+        ];
 
-                    inner1
-                }; 
-                let new_adder_inner = new_adder_outer(1, 2); 
-                let adder = new_adder_inner(3); 
-                adder(8); 
-                "#
-                .into(),
-                big_dec!(14),
-            ),
-            // 测试3: 多层变量捕获
-            VmTestCase::<BigDecimal>::new(
-                r#"
-                let a = 1; 
-                func new_adder_outer(b) { 
-                    func inner1(c) { 
-                        func inner2(d) { a + b + c + d }; 
+        run_vm_error_tests("", &error_cases);
+    }
 
-                        inner2
-                    };  
+    #[test]
+    fn test_type_annotations() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("func f(n: Int) -> Int { n * 2 } f(2)".into(), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new("func f(n: Int | Double, s: String = 1) { n } f(1)".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new("class P { let x = 1; } class Q : P {} func f(p: P) { p.x } f(copy(Q))".into(), big_dec!(1)),
+            VmTestCase::<BigDecimal>::new("func f(x: Any) { x } f(3)".into(), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new("#[unchecked] func f(n: Int) -> Int { len(n) } f(\"ab\")".into(), big_dec!(2)),
+        ];
 
-                    inner1
-                }; 
-                let new_adder_inner = new_adder_outer(2); 
-                let adder = new_adder_inner(3); 
-                adder(8); 
-                "#
-                .into(),
-                big_dec!(14),
-            ),
-            // 测试4: 多个闭包组合
-            VmTestCase::<BigDecimal>::new(
-                r#"
-                func new_closure(a, b) { 
-                    func param1() { a; }; 
-                    func param2() { b; }; 
-                    func last_func() { param1() + param2(); }; 
+        run_vm_tests(tests);
 
-                    last_func
-                }; 
-                let closure = new_closure(9, 90); 
-                closure(); 
-                "#
-                .into(),
-                big_dec!(99),
-            ),
-            VmTestCase::new(
-                r#"
-                func tinyjoker() {
-                    func lengthdown(x) {
-                        if x == 0 {
-                            0
-                        } else {
-                            lengthdown(x - 1)
-                        }
-                    }
+        let error_cases = [
+            (r#"func f(n: Int) {} f("x");"#, "argument 'n' of f expected Int, got String"),
+            ("func f(s: String | None = none) {} f(1);", "argument 's' of f expected String | None, got Int"),
+            ("func f(n) -> String { n } f(1);", "return value of f expected String, got Int"),
+            ("func f() -> Int { let a = 1 } f();", "return value of f expected Int, got None"),
+            ("class P {} func f(p: P) {} f(1);", "argument 'p' of f expected P, got Int"),
+            ("sorted(1);", "argument 0 of native function expected Array, got Int"),
+        ];
 
-                    lengthdown(101)
-                }
+        run_vm_error_tests("", &error_cases);
+    }
 
-                tinyjoker();
-                "#
-                .into(),
-                big_dec!(0),
+    #[test]
+    fn test_readonly() {
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new("const LIMIT = 10; LIMIT + 1".into(), big_dec!(11)),
+            VmTestCase::<BigDecimal>::new("readonly let n = 2; func f() { const k = 3; k * n } f()".into(), big_dec!(6)),
+            VmTestCase::<BigDecimal>::new(
+                "class P { readonly let id = 7; let v = 1; } let p = copy(P); p.v = 5; p.id + p.v".into(),
+                big_dec!(12),
             ),
+            // match 分支内的绑定遮蔽常量, 分支结束后常量不变
+            VmTestCase::<BigDecimal>::new("const a = 1; let r = match 5 { a => a * 2 }; r + a".into(), big_dec!(11)),
+            VmTestCase::<BigDecimal>::new("const A = 1; match [2] { [A] => A }".into(), big_dec!(2)),
         ];
 
-        run_vm_tests(tests)
+        run_vm_tests(tests);
+
+        let error_cases = [
+            ("class P { readonly let id = 7; } let p = copy(P); p.id = 1;", "cannot assign to readonly field: id"),
+            ("class P { readonly let id = 7; } class Q : P {} let q = copy(Q); q.id += 1;", "cannot assign to readonly field: id"),
+        ];
+
+        run_vm_error_tests("", &error_cases);
+
+        // 重新赋值与重复定义在编译期被拒绝
+        for code in [
+            "const A = 1; A = 2",
+            "const A = 1; A += 2",
+            "const A = 1; let A = 2",
+            "func f() { const b = 1; func () { b = 2 } }",
+            "const A = 1; let [A] = [2]",
+            "const A = 1; #[unchecked] func A() {}",
+            "const A = 1; for A in [2] {}",
+            "const A = 1; try {} catch A {}",
+            "const A = 1; func A() {}",
+            "const A = 1; class A {}",
+            "const A = 1; enum A { X }",
+        ] {
+            assert!(compile_it(code.into(), "__test_readonly__".into()).is_err(), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_private_members() {
+        let account = r#"
+            class Account {
+                private let balance = 100;
+                public let owner = "ant";
+
+                #[method]
+                private let audit = func (self) { self.balance * 2 };
+
+                #[method]
+                let deposit = func (self, n) { self.balance += n; self.audit() };
+            }
+            class Sub : Account {
+                #[method]
+                let peek = func (self) { self.balance };
+            }
+            let a = copy(Account);
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(format!("{account} a.deposit(5)"), big_dec!(210)),
+            VmTestCase::<BigDecimal>::new(format!("{account} copy(Sub).deposit(1)"), big_dec!(202)),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::new(format!("{account} a.owner"), String::from("ant")),
+            VmTestCase::new("class H { private let x = 1; } str(H)".into(), String::from("class H {}")),
+            VmTestCase::new(
+                format!(r#"{account} match a {{ Account {{ balance: b }} => "leak", _ => "hidden" }}"#),
+                String::from("hidden"),
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        let balance = "cannot access private member 'balance' of class Account";
+
+        run_vm_error_tests(
+            account,
+            &[
+                ("a.balance;", balance),
+                ("a.balance = 1;", balance),
+                ("a.balance += 1;", balance),
+                ("a.audit();", "cannot access private member 'audit' of class Account"),
+                ("Account::balance;", balance),
+                ("copy(Sub).peek();", balance),
+            ],
+        );
+
+        assert!(compile_it("private let x = 1".into(), "__test_private_members__".into()).is_err());
+    }
+
+    #[test]
+    fn test_operator_overloading() {
+        let vec2 = r#"
+            class Vec2 {
+                let x = 0;
+                let y = 0;
+
+                #[method]
+                let with = func (self, x, y) { let v = copy(self); v.x = x; v.y = y; v };
+                #[method]
+                let __add__ = func (self, o) { self.with(self.x + o.x, self.y + o.y) };
+                #[method]
+                let __mul__ = func (self, k) { self.with(self.x * k, self.y * k) };
+                #[method]
+                let __eq__ = func (self, o) { self.x == o.x and self.y == o.y };
+                #[method]
+                let __lt__ = func (self, o) { self.x + self.y < o.x + o.y };
+                #[method]
+                let __neg__ = func (self) { self.with(-self.x, -self.y) };
+                #[method]
+                let __not__ = func (self) { self.x == 0 and self.y == 0 };
+                #[method]
+                let __index__ = func (self, i) { if i == 0 { self.x } else { self.y } };
+            }
+            let a = Vec2.with(1, 2);
+            let b = Vec2.with(3, 4);
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(format!("{vec2} (a + b * 2)[1]"), big_dec!(10)),
+            VmTestCase::<BigDecimal>::new(format!("{vec2} (-a)[0]"), big_dec!(-1)),
+            VmTestCase::<BigDecimal>::new(format!("{vec2} let c = a; c += b; c[0]"), big_dec!(4)),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::new(format!("{vec2} a == Vec2.with(1, 2)"), true),
+            VmTestCase::new(format!("{vec2} a != b"), true),
+            VmTestCase::new(format!("{vec2} a < b"), true),
+            VmTestCase::new(format!("{vec2} !Vec2"), true),
+        ];
+
+        run_vm_tests(tests2);
+    }
+
+    #[test]
+    fn test_object_hooks() {
+        let record = r#"
+            class Record {
+                let age = 0;
+
+                #[method]
+                let __set__ = func (self, name, value) {
+                    if value < 0 { panic("age must not be negative") }
+                    self.age = value
+                };
+            }
+            let r = copy(Record);
+        "#;
+
+        let adder = r#"
+            class Adder {
+                let base = 10;
+
+                #[method]
+                let __call__ = func (self, x, k = 0) { self.base + x + k };
+            }
+            let add = copy(Adder);
+        "#;
+
+        let store = r#"
+            class Store {
+                let value = 0;
+
+                #[method]
+                let __setindex__ = func (self, key, value) { self.value = key + value };
+                #[method]
+                let __index__ = func (self, key) { self.value };
+            }
+            let s = copy(Store);
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(format!("{record} r.age = 5; r.age"), big_dec!(5)),
+            VmTestCase::<BigDecimal>::new(
+                format!("{record} r.age = 5; try {{ r.age = -1 }} catch e {{}} r.age"),
+                big_dec!(5),
+            ),
+            VmTestCase::<BigDecimal>::new(format!("{adder} add(5)"), big_dec!(15)),
+            VmTestCase::<BigDecimal>::new(format!("{adder} add(5, k: 100)"), big_dec!(115)),
+            VmTestCase::<BigDecimal>::new(format!("{store} s[1] = 20; s[0]"), big_dec!(21)),
+            VmTestCase::<BigDecimal>::new(format!("{store} s[1] = 20; s[0] += 1; s[0]"), big_dec!(22)),
+        ];
+
+        run_vm_tests(tests);
+
+        run_vm_error_tests(record, &[("r.age = -1", "age must not be negative")]);
+    }
+
+    #[test]
+    fn test_generators() {
+        let count = r#"
+            func count(n) {
+                let i = 0
+                while i < n {
+                    yield i
+                    i += 1
+                }
+            }
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(
+                format!("{count} let s = 0; for x in count(5) {{ s += x }} s"),
+                big_dec!(10),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                format!("{count} let g = count(3); g.next(); g.next().value"),
+                big_dec!(1),
+            ),
+            // 生成器的嵌套与提前 return
+            VmTestCase::<BigDecimal>::new(
+                format!(r#"
+                    {count}
+                    func evens(g) {{ for x in g {{ if x % 2 == 0 {{ yield x }} }} }}
+                    func until(g, n) {{ for x in g {{ if x == n {{ return 0 }} yield x }} }}
+                    let s = 0
+                    for x in evens(until(count(100), 7)) {{ s += x }}
+                    s
+                "#),
+                big_dec!(12),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                class Bag {
+                    let items = [1, 2, 3];
+
+                    #[method]
+                    let each = func (self) { for x in self.items { yield x * 10 } };
+                }
+                let b = copy(Bag)
+                let s = 0
+                for x in b.each() { s += x }
+                s
+                "#.into(),
+                big_dec!(60),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::<bool>::new(
+                format!("{count} let g = count(1); g.next(); g.next().is_null"),
+                true,
+            ),
+            // 未捕获的错误结束生成器
+            VmTestCase::<bool>::new(
+                r#"
+                func bad() { yield 1; panic("bad generator") }
+                let g = bad()
+                g.next()
+                try { g.next() } catch e {}
+                g.next().is_null
+                "#.into(),
+                true,
+            ),
+            VmTestCase::<bool>::new(
+                format!("{count} let g = count(2); g.next(); g.result().is_null"),
+                true,
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        let tests3 = vec![
+            // 挂起期间异常处理器与局部变量被保存
+            VmTestCase::<String>::new(
+                r#"
+                func safe() {
+                    try {
+                        yield "a"
+                        panic("oops")
+                    } catch e {
+                        yield "b"
+                    }
+                    yield "c"
+                }
+                let s = ""
+                for x in safe() { s = s + x }
+                s
+                "#.into(),
+                "abc".into(),
+            ),
+        ];
+
+        run_vm_tests(tests3);
+
+        // 挂起期间闭包读写被捕获的局部变量; return 的值经过类型检查并可通过 result() 取得
+        let tests4 = vec![
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func g() { let n = 0; let get = func () { n }; yield get; n = 5; yield get() }
+                let it = g()
+                let get = it.next().value
+                it.next().value * 10 + get()
+                "#.into(),
+                big_dec!(55),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                "func g() { let n = 0; yield func (v) { n = v }; yield n } let it = g(); it.next().value(7); it.next().value".into(),
+                big_dec!(7),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                "func g() { yield 1; return 9 } let it = g(); for x in it {} it.result().value".into(),
+                big_dec!(9),
+            ),
+            VmTestCase::<BigDecimal>::new(
+                "let s = 0; func g() { try { yield 1; return 2 } finally { s = 10 } } let it = g(); for x in it {} s + it.result().value".into(),
+                big_dec!(12),
+            ),
+        ];
+
+        run_vm_tests(tests4);
+
+        run_vm_error_tests(
+            r#"func bad() { yield 1; panic("bad generator") } func t() -> Int { yield 1; return "x" }"#,
+            &[
+                ("for x in bad() {}", "bad generator"),
+                ("for x in t() {}", "return value of t expected Int, got String"),
+            ],
+        );
+
+        assert!(compile_it("yield 1".into(), "__test_yield__".into()).is_err());
+    }
+
+    #[test]
+    fn test_enums() {
+        let shape = "enum Shape { Circle(r), Rect(w, h), Empty }";
+
+        let area = r#"
+            func area(s) {
+                match s {
+                    Circle { r } => 3 * r * r,
+                    Rect { w, h } => w * h,
+                    Empty {} => 0
+                }
+            }
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(format!("{shape} Shape.Circle(2).r"), big_dec!(2)),
+            VmTestCase::<BigDecimal>::new(format!("{shape} Shape::Rect(h: 4, w: 3).w"), big_dec!(3)),
+            VmTestCase::<BigDecimal>::new(format!("{shape} {area} area(Shape.Circle(2))"), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new(format!("{shape} {area} area(Shape.Rect(3, 4))"), big_dec!(12)),
+            VmTestCase::<BigDecimal>::new(format!("{shape} {area} area(Shape.Empty)"), big_dec!(0)),
+            // 状态机
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                enum State { Idle, Running(n), Done }
+                func step(s) {
+                    match s {
+                        Idle {} => State.Running(0),
+                        Running { n } if n < 2 => State.Running(n + 1),
+                        _ => State.Done
+                    }
+                }
+                let s = State.Idle
+                let steps = 0
+                while s != State.Done { s = step(s); steps += 1 }
+                steps
+                "#.into(),
+                big_dec!(4),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::<bool>::new(format!("{shape} Shape.Circle(2) == Shape.Circle(2)"), true),
+            VmTestCase::<bool>::new(format!("{shape} Shape.Circle(2) != Shape.Circle(3)"), true),
+            VmTestCase::<bool>::new(format!("{shape} Shape.Empty == Shape.Empty"), true),
+            VmTestCase::<bool>::new(format!("{shape} Shape.Empty == 0"), false),
+            VmTestCase::<bool>::new(
+                format!("{shape} enum Other {{ Empty }} Shape.Empty == Other.Empty"),
+                false,
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        let tests3 = vec![
+            VmTestCase::<String>::new(format!("{shape} str(Shape.Rect(1, \"x\"))"), r#"Rect(1, "x")"#.into()),
+            VmTestCase::<String>::new(format!("{shape} str(Shape.Empty)"), "Empty".into()),
+            VmTestCase::<String>::new(
+                format!(r#"{shape} let m = {{}}; m[Shape.Circle(1)] = "one"; m[Shape.Circle(1)]"#),
+                "one".into(),
+            ),
+        ];
+
+        run_vm_tests(tests3);
+
+        run_vm_error_tests(
+            &format!("{shape} func f(s: Shape) {{ s }}"),
+            &[
+                ("f(1)", "argument 's' of f expected Shape, got Int"),
+                ("Shape.Empty = 1", "cannot assign to readonly field: Empty"),
+            ],
+        );
+
+        // 限定的变体模式按所属枚举与变体匹配
+        let which = r#"
+            enum State { Idle, Running(n), Done }
+            enum Job { Done, Failed(reason) }
+            class Done {}
+
+            func which(v) {
+                match v {
+                    State.Done => "state done",
+                    Job::Done => "job done",
+                    Job.Failed { reason } => "failed " + reason,
+                    State.Running { n: 3 } => "three",
+                    State.Running {} => "running",
+                    _ => "other"
+                }
+            }
+        "#;
+
+        let cases = [
+            ("State.Done", "state done"),
+            ("Job.Done", "job done"),
+            (r#"Job.Failed("x")"#, "failed x"),
+            ("State.Running(3)", "three"),
+            ("State.Running(4)", "running"),
+            ("State.Idle", "other"),
+            ("copy(Done)", "other"),
+        ];
+
+        let tests4 = cases
+            .iter()
+            .map(|(arg, expected)| VmTestCase::new(format!("{which} which({arg})"), String::from(*expected)))
+            .collect::<Vec<_>>();
+
+        run_vm_tests(tests4);
+
+        run_vm_error_tests(which, &[("match 1 { State.Nope => 1, _ => 2 }", "enum State has no variant 'Nope'")]);
+
+        assert!(compile_it("match 1 { Nope.Done => 1 }".into(), "__test_enums__".into()).is_err());
+    }
+
+    #[test]
+    fn test_interfaces() {
+        let shape = r#"
+            interface Shape {
+                let area;
+                #[method]
+                let describe = func (self) { "area: " + str(self.area()) }
+            }
+            class Square : Shape {
+                let side = 2;
+                #[method]
+                let area = func (self) { self.side * self.side }
+            }
+        "#;
+
+        let tests = vec![
+            VmTestCase::<BigDecimal>::new(format!("{shape} copy(Square).area()"), big_dec!(4)),
+            VmTestCase::<BigDecimal>::new(
+                format!("{shape} class Big : Square {{ let side = 3 }} copy(Big).area()"),
+                big_dec!(9),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![
+            VmTestCase::<bool>::new(format!("{shape} implements(copy(Square), Shape)"), true),
+            VmTestCase::<bool>::new(
+                format!("{shape} class Other {{ let area = func () {{ 1 }} }} implements(Other, Shape)"),
+                true,
+            ),
+            VmTestCase::<bool>::new(format!("{shape} class Other {{}} implements(Other, Shape)"), false),
+            VmTestCase::<bool>::new(format!("{shape} implements(1, Shape)"), false),
+            // 没有声明成员的标记接口只能通过继承实现
+            VmTestCase::<bool>::new("interface Marker {} class A {} implements(copy(A), Marker)".into(), false),
+            VmTestCase::<bool>::new("interface Marker {} class B : Marker {} implements(copy(B), Marker)".into(), true),
+            // 没有值的成员以换行结尾
+            VmTestCase::<bool>::new(
+                "interface Named {\n let area\n let name\n}\nclass N { let area = 1; let name = 2 }\nimplements(N, Named)".into(),
+                true,
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        let tests3 = vec![
+            VmTestCase::<String>::new(format!("{shape} copy(Square).describe()"), "area: 4".into()),
+        ];
+
+        run_vm_tests(tests3);
+
+        run_vm_error_tests(
+            shape,
+            &[
+                ("class Bad : Shape { let side = 1 }", "class 'Bad' must define member 'area' declared in 'Shape'"),
+                ("copy(Shape)", "cannot instantiate interface 'Shape'"),
+                ("implements(Square, Square)", "expected an interface, got: class Square { ... }"),
+            ],
+        );
+
+        // 未定义的成员只能作为接口的直接成员声明
+        for code in [
+            "let x;",
+            "func f() { let x; x }",
+            "class A { let x; }",
+            "class A { #[method] let f = func (self) { let x; x }; }",
+            "interface I { let f = func () { let x; x }; }",
+            "let x\nlet y = 2",
+            "let x 5",
+            "let x: int 5",
+        ] {
+            assert!(compile_it(code.into(), "__test_interfaces__".into()).is_err(), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_closures() {
+        let tests = vec![
+            // 测试1: 基本闭包
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func new_adder(a, b) {
+                    func inner(c) {
+                        a + b + c
+                    }
+
+                    inner
+                }
+
+                let adder = new_adder(1, 2);
+                adder(8);
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+            // 测试2: 带局部变量的闭包
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func new_adder(a, b) { 
+                    let c = a + b; 
+                    func inner(d) { c + d }; 
+
+                    inner
+                }; 
+                let adder = new_adder(1, 2); 
+                adder(8); 
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+            // 测试1: 多层闭包
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func new_adder_outer(a, b) { 
+                    let c = a + b; 
+                    func inner1(d) { 
+                        let e = d + c; 
+                        func inner2(f) { e + f; }; 
+
+                        inner2
+                    }; 
+
+                    inner1
+                }; 
+                let new_adder_inner = new_adder_outer(1, 2); 
+                let adder = new_adder_inner(3); 
+                adder(8); 
+                "#
+                .into(),
+                big_dec!(14),
+            ),
+            // 测试3: 多层变量捕获
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                let a = 1; 
+                func new_adder_outer(b) { 
+                    func inner1(c) { 
+                        func inner2(d) { a + b + c + d }; 
+
+                        inner2
+                    };  
+
+                    inner1
+                }; 
+                let new_adder_inner = new_adder_outer(2); 
+                let adder = new_adder_inner(3); 
+                adder(8); 
+                "#
+                .into(),
+                big_dec!(14),
+            ),
+            // 测试4: 多个闭包组合
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func new_closure(a, b) { 
+                    func param1() { a; }; 
+                    func param2() { b; }; 
+                    func last_func() { param1() + param2(); }; 
+
+                    last_func
+                }; 
+                let closure = new_closure(9, 90); 
+                closure(); 
+                "#
+                .into(),
+                big_dec!(99),
+            ),
+            VmTestCase::new(
+                r#"
+                func tinyjoker() {
+                    func lengthdown(x) {
+                        if x == 0 {
+                            0
+                        } else {
+                            lengthdown(x - 1)
+                        }
+                    }
+
+                    lengthdown(101)
+                }
+
+                tinyjoker();
+                "#
+                .into(),
+                big_dec!(0),
+            ),
+            // 测试5: 闭包修改被捕获的变量
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func make() {
+                    let n = 0
+                    func () { n = n + 1; n }
+                }
+
+                let c = make();
+                c();
+                c();
+                "#
+                .into(),
+                big_dec!(2),
+            ),
+            // 测试6: 多个闭包与外层函数共享同一变量
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func make() {
+                    let v = 10
+                    let inc = func () { v += 5 }
+                    let get = func () { v }
+                    inc();
+                    [inc, get]
+                }
+
+                let fs = make();
+                fs[0]();
+                fs[1]();
+                "#
+                .into(),
+                big_dec!(20),
+            ),
+            // 测试7: 多层闭包写入外层变量
+            VmTestCase::<BigDecimal>::new(
+                r#"
+                func outer() {
+                    let x = 1
+                    let f = func () { func () { x = x * 2 } }()
+                    f();
+                    f();
+                    x
+                }
+
+                outer();
+                "#
+                .into(),
+                big_dec!(4),
+            ),
+        ];
+
+        run_vm_tests(tests)
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let tests = vec![
+            VmTestCase::new(
+                r#"
+                let sum = 0;
+                for i in range(5) { sum = sum + i }
+                sum
+                "#
+                .into(),
+                big_dec!(10),
+            ),
+            VmTestCase::new(
+                r#"
+                let sum = 0;
+                for i in [1, 2, 3] { sum = sum + i }
+                sum
+                "#
+                .into(),
+                big_dec!(6),
+            ),
+            VmTestCase::new(
+                r#"
+                let sum = 0;
+                for k in {1: "a", 2: "b"} { sum = sum + k }
+                sum
+                "#
+                .into(),
+                big_dec!(3),
+            ),
+            // break / continue
+            VmTestCase::new(
+                r#"
+                let sum = 0;
+                for i in range(10) {
+                    if i == 2 { continue }
+                    if i == 5 { break }
+                    sum = sum + i
+                }
+                sum
+                "#
+                .into(),
+                big_dec!(8),
+            ),
+            // 嵌套循环
+            VmTestCase::new(
+                r#"
+                let sum = 0;
+                for i in [1, 2] {
+                    for j in [10, 20] {
+                        if j == 20 { break }
+                        sum = sum + i * j
+                    }
+                }
+                sum
+                "#
+                .into(),
+                big_dec!(30),
+            ),
+            // 函数内的循环
+            VmTestCase::new(
+                r#"
+                func total(arr) {
+                    let sum = 0;
+                    for i in arr { sum = sum + i }
+                    sum
+                }
+                total([4, 5, 6])
+                "#
+                .into(),
+                big_dec!(15),
+            ),
+            // 自定义迭代器
+            VmTestCase::new(
+                r#"
+                class Countdown {
+                    let n = 3;
+
+                    #[method]
+                    let next = func (self) {
+                        if self.n == 0 {
+                            Null
+                        } else {
+                            self.n = self.n - 1
+                            Some(self.n + 1)
+                        }
+                    }
+                }
+                let sum = 0;
+                for i in copy(Countdown) { sum = sum + i }
+                sum
+                "#
+                .into(),
+                big_dec!(6),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        let tests2 = vec![VmTestCase::new(
+            r#"
+            let s = "";
+            for c in "abc" { s = c + s }
+            s
+            "#
+            .into(),
+            String::from("cba"),
+        )];
+
+        run_vm_tests(tests2);
+    }
+
+    #[test]
+    fn test_try_catch() {
+        let tests = vec![
+            VmTestCase::new(
+                r#"
+                let r = 0;
+                try { int("abc"); r = 1 } catch e { r = 2 }
+                r
+                "#
+                .into(),
+                big_dec!(2),
+            ),
+            VmTestCase::new(
+                r#"
+                let r = 0;
+                try { r = 1 } catch e { r = 2 } finally { r = r + 10 }
+                r
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+            // 跨栈帧展开
+            VmTestCase::new(
+                r#"
+                func f(x) { int(x) }
+                func g() {
+                    try { f("x") } catch e { return 1 }
+                    2
+                }
+                g()
+                "#
+                .into(),
+                big_dec!(1),
+            ),
+            // 没有 catch 时执行 finally 后重新抛出
+            VmTestCase::new(
+                r#"
+                let n = 0;
+                try {
+                    try { panic("inner") } finally { n = n + 1 }
+                } catch { n = n + 10 }
+                n
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+            // catch 中抛出的错误也会先执行 finally
+            VmTestCase::new(
+                r#"
+                let n = 0;
+                try {
+                    try { panic("a") } catch { panic("b") } finally { n = n + 1 }
+                } catch { n = n + 10 }
+                n
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+            // break 跳出 try 块时弹出异常处理器
+            VmTestCase::new(
+                r#"
+                let c = 0;
+                for i in range(5) {
+                    try {
+                        if i == 3 { break }
+                        c = c + 1
+                    } catch { }
+                }
+                try { panic("after loop") } catch { c = c + 100 }
+                c
+                "#
+                .into(),
+                big_dec!(103),
+            ),
+            // break / continue 离开 try 块时执行 finally
+            VmTestCase::new(
+                r#"
+                let n = 0;
+                for i in range(5) {
+                    try {
+                        if i == 1 { continue }
+                        if i == 3 { break }
+                    } finally { n = n + 1 }
+                }
+                n
+                "#
+                .into(),
+                big_dec!(4),
+            ),
+            // return 由内到外执行 finally, 返回值不受影响
+            VmTestCase::new(
+                r#"
+                let n = 0;
+                func f() {
+                    try {
+                        try { return 1 } finally { n = n + 10 }
+                    } finally { n = n + 100 }
+                }
+                f() + n
+                "#
+                .into(),
+                big_dec!(111),
+            ),
+            // catch 块中 return 同样执行 finally
+            VmTestCase::new(
+                r#"
+                let n = 0;
+                func f() {
+                    try { panic("x") } catch { return 1 } finally { n = n + 10 }
+                }
+                f() + n
+                "#
+                .into(),
+                big_dec!(11),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        // 错误对象的 message 不含虚拟机添加的前缀
+        let tests2 = vec![
+            VmTestCase::new(
+                r#"
+                let m = "";
+                try { panic("boom") } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("boom"),
+            ),
+            VmTestCase::new(
+                r#"
+                let m = "";
+                func f() { panic("deep") }
+                try { f() } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("deep"),
+            ),
+            VmTestCase::new(
+                r#"
+                let m = "";
+                try { 1 / 0 } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("division by zero"),
+            ),
+            VmTestCase::new(
+                r#"
+                let m = "";
+                func f(a) {}
+                func g() { f() }
+                try { g() } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("expected 1 args, got 0 args"),
+            ),
+        ];
+
+        run_vm_tests(tests2);
+
+        // 被捕获的错误不会把信息留给之后的错误
+        let tests3 = vec![
+            VmTestCase::new(
+                r#"
+                let m = "";
+                try { 1 / 0 } catch { }
+                try { let a = [1]; a[5] } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("index out of range, index: 5, array length: 1"),
+            ),
+            VmTestCase::new(
+                r#"
+                let m = "";
+                func f() { try { panic("first") } catch { } 1 % 0 }
+                try { f() } catch e { m = e.message }
+                m
+                "#
+                .into(),
+                String::from("modulo by zero"),
+            ),
+        ];
+
+        run_vm_tests(tests3);
+    }
+
+    #[test]
+    fn test_import() {
+        let tests = vec![
+            VmTestCase::new(
+                r#"
+                from test_code.core_feature.mod1 import bro_give_you
+                bro_give_you
+                "#
+                .into(),
+                String::from("bro give you"),
+            ),
+            VmTestCase::new(
+                r#"
+                from test_code.core_feature.mod1 import bro_give_you as a, man as b
+                a + b()
+                "#
+                .into(),
+                String::from("bro give youbro give you"),
+            ),
+            VmTestCase::new(
+                r#"
+                import test_code.core_feature.mod1
+                mod1.man()
+                "#
+                .into(),
+                String::from("bro give you"),
+            ),
+            VmTestCase::new(
+                r#"
+                func f() {
+                    import test_code.core_feature.mod1 as m
+                    m.bro_give_you
+                }
+                f()
+                "#
+                .into(),
+                String::from("bro give you"),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        run_vm_error_tests(
+            "",
+            &[("from test_code.core_feature.mod1 import nope", "cannot import name 'nope' from module 'mod1'")],
+        );
+    }
+
+    #[test]
+    fn test_inheritance() {
+        let tests = vec![
+            VmTestCase::new(
+                r#"
+                class A {
+                    let v = 1;
+                    #[method]
+                    let f = func (self) { self.v + 10 };
+                }
+                class B : A {}
+                let b = copy(B);
+                b.f() + b.v
+                "#
+                .into(),
+                big_dec!(12),
+            ),
+            VmTestCase::new(
+                r#"
+                class A {
+                    #[method]
+                    let f = func (self) { 1 };
+                }
+                class B : A {
+                    #[method]
+                    let f = func (self) { 2 };
+                }
+                copy(B).f()
+                "#
+                .into(),
+                big_dec!(2),
+            ),
+            VmTestCase::new(
+                r#"
+                class A {
+                    let v = 1;
+                    #[method]
+                    let f = func (self) { self.v + 10 };
+                }
+                class B : A {
+                    #[method]
+                    let f = func (self) { super.f() + 100 };
+                }
+                class C : B {}
+                let c = copy(C);
+                c.v = 2;
+                c.f()
+                "#
+                .into(),
+                big_dec!(112),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        run_vm_error_tests("", &[("let a = 1; class B : a {}", "expected an class to inherit, got: 1")]);
+    }
+
+    #[test]
+    fn test_class_member() {
+        let tests = vec![
+            VmTestCase::new(
+                r#"
+                class A {
+                    let x = 1;
+                    let g = func (a) { a + 1 };
+                }
+                A::g(A::x)
+                "#
+                .into(),
+                big_dec!(2),
+            ),
+            VmTestCase::new(
+                r#"
+                class A {
+                    let x = 1;
+                    #[method]
+                    let f = func (self) { self.x };
+                }
+                let a = copy(A);
+                a.x = 3;
+                A::f(a)
+                "#
+                .into(),
+                big_dec!(3),
+            ),
+            VmTestCase::new(
+                r#"
+                class A { let x = 1; }
+                class B : A {}
+                A::x = 5;
+                B::x
+                "#
+                .into(),
+                big_dec!(5),
+            ),
+        ];
+
+        run_vm_tests(tests);
+
+        run_vm_error_tests("class A {}", &[("A::nope", "class 'A' has no member 'nope'")]);
+    }
+
+    // 在 prelude 之后的 try 块中执行代码, 比较捕获到的错误对象的 message
+    fn run_vm_error_tests(prelude: &str, tests: &[(&str, &str)]) {
+        let tests = tests
+            .iter()
+            .map(|(code, expected)| {
+                VmTestCase::new(
+                    format!(r#"{prelude} let msg = ""; try {{ {code} }} catch e {{ msg = e.message }} msg"#),
+                    String::from(*expected),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        run_vm_tests(tests);
     }
 
     fn run_vm_tests<T: Debug + Clone>(tests: Vec<VmTestCase<T>>) {
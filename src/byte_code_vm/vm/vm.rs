@@ -6,31 +6,38 @@ use crate::{
     builtin::builtin_map::{BUILTIN_MAP, BUILTIN_MAP_INDEX},
     byte_code_vm::{
         code::code::{
-            OP_ADD, OP_AND, OP_ARRAY, OP_BANG, OP_CALL, OP_CLASS, OP_CLOSURE, OP_CONSTANTS,
-            OP_CURRENT_CLOSURE, OP_FALSE, OP_GET_BUILTIN, OP_GET_FIELD, OP_GET_FREE, OP_GET_GLOBAL,
-            OP_GET_LOCAL, OP_HASH, OP_INDEX, OP_JUMP, OP_JUMP_NOT_TRUTHY, OP_LOAD_MODULE, OP_MINUS,
-            OP_NONE, OP_NOTEQ, OP_OR, OP_POP, OP_RETURN, OP_RETURN_VALUE, OP_SET_FIELD,
-            OP_SET_GLOBAL, OP_SET_INDEX, OP_SET_LOCAL, OP_TEST_PRINT, OP_TRUE, OpCode, read_uint16,
+            OP_ADD, OP_ARRAY, OP_BANG, OP_CALL, OP_CLASS, OP_CLOSURE, OP_CONSTANTS,
+            OP_CURRENT_CLOSURE, OP_FALSE, OP_GET_BUILTIN, OP_GET_CLASS_MEMBER, OP_GET_FIELD, OP_GET_FREE, OP_GET_GLOBAL, OP_SET_FREE, OP_GET_SELF_FIELD, OP_SET_SELF_FIELD,
+            OP_GET_ITER, OP_GET_LOCAL, OP_GET_SUPER, OP_HASH, OP_IMPORT_MEMBER, OP_INDEX, OP_INHERIT, OP_ITER_NEXT, OP_JUMP, OP_JUMP_IF_FALSE_OR_POP, OP_JUMP_IF_TRUE_OR_POP, OP_JUMP_NOT_TRUTHY, OP_LOAD_MODULE, OP_MINUS, OP_MOD, OP_SHR, OP_BIT_NOT, OP_LE, OP_GE, OP_DUP, OP_REVERSE, OP_FORMAT_VALUE, OP_BUILD_STRING, OP_MATCH_EQ, OP_MATCH_ARRAY, OP_MATCH_HASH, OP_MATCH_CLASS, OP_MATCH_HAS_KEY, OP_MATCH_FAIL, OP_CHECK_ARRAY, OP_CHECK_HASH, OP_CHECK_INSTANCE, OP_ARRAY_REST, OP_TUPLE, OP_CALL_KW, OP_JUMP_IF_BOUND,
+            OP_NONE, OP_NOTEQ, OP_POP, OP_POP_TRY, OP_RETURN, OP_RETURN_VALUE, OP_SET_FIELD,
+            OP_SET_GLOBAL, OP_SET_INDEX, OP_SET_LOCAL, OP_SETUP_TRY, OP_TEST_PRINT, OP_THROW, OP_TRUE, OP_YIELD, OP_ENUM_VALUE, OP_ENUM, OP_MATCH_VARIANT, OpCode, read_uint16, infix_operator_symbol, prefix_operator_symbol,
         },
         compiler::compiler::ByteCode,
         constants::{FALSE_OBJ, NONE_OBJ, TRUE_OBJ, UNINIT_OBJECT},
-        utils::native_boolean_to_object,
         vm::{
             eval_functions::{
-                eval_array_literal_utils::build_array, eval_class_utils::build_class,
-                eval_hash_literal_utils::build_hash_map,
+                eval_array_literal_utils::build_array,
+                eval_class_utils::{build_class, check_field_access, check_members_defined},
+                eval_dunder::{call_dunder, call_dunder_discard, eval_infix_dunder, prefix_dunder},
+                eval_destructure::{array_rest, check_array, check_hash, check_instance},
+                eval_format::format_value, eval_hash_literal_utils::build_hash_map,
+                eval_match::{match_array, match_class, match_eq, match_has_key, match_hash, match_variant},
                 eval_index_expression::eval_index_expression,
+                eval_iter::{eval_get_iter, eval_iter_next},
                 eval_infix_operator::eval_infix_operator, eval_obj_member::eval_obj_member,
                 eval_prefix_operator::eval_prefix_operator, eval_set_index::eval_set_index,
+                eval_type_check::check_type_hint,
             },
-            frame::Frame,
+            frame::{ExceptionHandler, Frame},
             function_utils::{self, push_closure},
+            generator_utils::suspend_generator,
         },
     },
     module_importer::importer_enum::ModuleImporter,
     obj_enum::object::Object,
     object::{
-        ant_closure::Closure, ant_compiled_function::CompiledFunction, object::IAntObject,
+        ant_closure::{Closure, Upvalue}, ant_compiled_function::CompiledFunction, ant_enum_value::AntEnumValue, ant_error::AntError, ant_string::AntString, ant_tuple::AntTuple,
+        object::IAntObject,
         utils::rrc_is_truthy,
     },
     rc_ref_cell,
@@ -53,6 +60,15 @@ pub struct Vm<'a> {
     pub sp: usize, // stack next pos
 
     pub global_count: usize,
+
+    // 由 OpThrow 抛出的错误对象, 在 handle_error 中取出
+    pub thrown: Option<Object>,
+
+    // 错误最初的信息 (不含各层 "error xxx: " 前缀), 作为错误对象的 message
+    pub error_message: Option<String>,
+
+    // 仍指向栈上局部变量的 upvalue, 同一变量的所有闭包共享同一个
+    pub open_upvalues: Vec<Rc<RefCell<Upvalue>>>,
 }
 
 impl<'a> Vm<'a> {
@@ -65,12 +81,17 @@ impl<'a> Vm<'a> {
             instructions: bytecode.instructions.into(),
             local_count: 0,
             param_count: 0,
+            params: Rc::default(),
+            return_type: None,
+            unchecked: false,
+            is_generator: false,
+            captures: Rc::default(),
             scope_info: bytecode.main_info,
         };
 
         let main_closure = Closure {
             func: main_func,
-            free: rc_ref_cell!(vec![]),
+            free: Rc::default(),
         };
 
         let main_frame = Frame::new(main_closure, 0);
@@ -84,6 +105,9 @@ impl<'a> Vm<'a> {
             frame_index: 1,
             sp: 0,
             global_count: bytecode.global_count,
+            thrown: None,
+            error_message: None,
+            open_upvalues: vec![],
         }
     }
 
@@ -94,12 +118,17 @@ impl<'a> Vm<'a> {
             instructions: bytecode.instructions.into(),
             local_count: 0,
             param_count: 0,
+            params: Rc::default(),
+            return_type: None,
+            unchecked: false,
+            is_generator: false,
+            captures: Rc::default(),
             scope_info: bytecode.main_info,
         };
 
         let main_closure = Closure {
             func: main_func,
-            free: rc_ref_cell!(vec![]),
+            free: Rc::default(),
         };
 
         let main_frame = Frame::new(main_closure, 0);
@@ -113,6 +142,9 @@ impl<'a> Vm<'a> {
             frames: vec![main_frame],
             frame_index: 1,
             sp: 0,
+            thrown: None,
+            error_message: None,
+            open_upvalues: vec![],
         }
     }
 
@@ -135,9 +167,42 @@ impl<'a> Vm<'a> {
     #[inline(always)]
     pub fn pop_frame(&mut self) -> &Frame {
         self.frame_index -= 1;
+
+        if !self.open_upvalues.is_empty() {
+            self.close_upvalues(self.frames[self.frame_index].base_pointer);
+        }
+
         &self.frames[self.frame_index]
     }
 
+    // 跳过字段指令的操作数, OpGetSelfField / OpSetSelfField 额外带有所在类名, 返回该类名
+    fn read_field_access_class(&mut self, op: OpCode, ip: usize, instructions: &[u8]) -> Option<String> {
+        if op != OP_GET_SELF_FIELD && op != OP_SET_SELF_FIELD {
+            self.current_frame().ip += 2;
+            return None;
+        }
+
+        let class_index = read_uint16(&instructions[(ip + 3)..]);
+        self.current_frame().ip += 4;
+
+        Some(self.constants[class_index as usize].borrow().inspect())
+    }
+
+    // 将位于 from 及以上栈位置的 upvalue 关闭, 之后闭包持有变量的值
+    pub fn close_upvalues(&mut self, from: usize) {
+        let stack = &self.stack;
+
+        self.open_upvalues.retain(|upvalue| {
+            let index = match &*upvalue.borrow() {
+                Upvalue::Open(index) if *index >= from => *index,
+                _ => return true,
+            };
+
+            *upvalue.borrow_mut() = Upvalue::Closed(stack[index].clone());
+            false
+        });
+    }
+
     #[inline(always)]
     pub fn next(&mut self, op: OpCode, ip: usize, instructions: Rc<[u8]>) -> Result<(), String> {
         match op {
@@ -154,7 +219,7 @@ impl<'a> Vm<'a> {
                 }
             }
 
-            OP_ADD..=OP_NOTEQ => {
+            OP_ADD..=OP_NOTEQ | OP_MOD..=OP_SHR | OP_LE | OP_GE => {
                 let right = match self.pop() {
                     Some(it) => it,
                     None => {
@@ -175,69 +240,218 @@ impl<'a> Vm<'a> {
                     }
                 };
 
+                // 类实例的运算符重载
+                if eval_infix_dunder(self, op, &left, &right)? {
+                    return Ok(());
+                }
+
                 let eval_operator_result = eval_infix_operator(op, left, right);
 
                 if let Err(err) = eval_operator_result {
-                    return Err(format!("error evaluating infix operator {}: {}", op, err));
+                    return Err(self.wrap_error(format!("error evaluating infix operator {}", infix_operator_symbol(op)), err));
                 }
 
                 self.push(rc_ref_cell!(eval_operator_result?))?
             }
 
-            OP_AND => {
-                let right = self.pop();
-                let left = self.pop();
+            // and / or 的短路求值: 决定结果的操作数留在栈上并跳转, 否则弹出继续求值右侧
+            OP_JUMP_IF_FALSE_OR_POP | OP_JUMP_IF_TRUE_OR_POP => {
+                let jump_to = read_uint16(&instructions[(ip + 1)..]);
 
-                if left.is_none() || right.is_none() {
-                    return Err(format!(
-                        "expected two objects of opcode {}. got {} objects",
-                        op,
-                        left.is_some() as u8 + right.is_some() as u8
-                    ));
+                let condition = match self.stack_top() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an condition")),
+                };
+
+                if rrc_is_truthy(&condition) == (op == OP_JUMP_IF_TRUE_OR_POP) {
+                    self.current_frame().ip = (jump_to as isize) - 1;
+                    return Ok(());
                 }
 
-                let left_obj = left.unwrap();
-                let right_obj = right.unwrap();
+                self.sp -= 1;
+                self.current_frame().ip += 2;
+            }
 
-                if !rrc_is_truthy(&left_obj) {
-                    self.push(rc_ref_cell!(native_boolean_to_object(false)))?;
-                    return Ok(());
+            // 复制栈顶的 n 个元素
+            OP_DUP => {
+                let count = instructions[ip + 1] as usize;
+                self.current_frame().ip += 1;
+
+                if self.sp < count {
+                    return Err(format!("expected {count} objects to duplicate"));
                 }
 
-                if !rrc_is_truthy(&right_obj) {
-                    self.push(rc_ref_cell!(native_boolean_to_object(false)))?;
-                    return Ok(());
+                for i in self.sp - count..self.sp {
+                    self.push(self.stack[i].clone())?;
                 }
+            }
 
-                self.push(rc_ref_cell!(native_boolean_to_object(true)))?;
+            // 反转栈顶的 n 个元素
+            OP_REVERSE => {
+                let count = instructions[ip + 1] as usize;
+                self.current_frame().ip += 1;
+
+                if self.sp < count {
+                    return Err(format!("expected {count} objects to reverse"));
+                }
+
+                let sp = self.sp;
+                self.stack[sp - count..sp].reverse();
             }
 
-            OP_OR => {
-                let right = self.pop();
-                let left = self.pop();
+            // 按格式说明符将栈顶对象转为字符串
+            OP_FORMAT_VALUE => {
+                let spec_index = read_uint16(&instructions[(ip + 1)..]);
+                self.current_frame().ip += 2;
 
-                if left.is_none() || right.is_none() {
-                    return Err(format!(
-                        "expected two objects of opcode {}. got {} objects",
-                        op,
-                        left.is_some() as u8 + right.is_some() as u8
-                    ));
+                let obj = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to format")),
+                };
+
+                let spec = match &*self.constants[spec_index as usize].borrow() {
+                    Object::AntString(it) => it.value.clone(),
+                    it => return Err(format!("expected an format specifier, got: {}", it.inspect())),
+                };
+
+                let s = format_value(&obj.borrow(), &spec)?;
+
+                self.push(rc_ref_cell!(Object::AntString(AntString::new(s))))?
+            }
+
+            // 模式匹配: 弹出被测对象 (与字面量/键), 压入是否匹配
+            OP_MATCH_EQ | OP_MATCH_HAS_KEY => {
+                let (right, left) = match (self.pop(), self.pop()) {
+                    (Some(r), Some(l)) => (r, l),
+                    _ => return Err(format!("expected two objects for opcode {op}")),
+                };
+
+                let matched = if op == OP_MATCH_EQ {
+                    match_eq(&left.borrow(), &right.borrow())
+                } else {
+                    match_has_key(&left.borrow(), &right.borrow())
+                };
+
+                self.push(rc_ref_cell!(if matched { TRUE_OBJ.clone() } else { FALSE_OBJ.clone() }))?
+            }
+
+            OP_MATCH_ARRAY | OP_MATCH_HASH | OP_MATCH_CLASS => {
+                let operand = if op == OP_MATCH_HASH {
+                    0
+                } else {
+                    let operand = read_uint16(&instructions[(ip + 1)..]) as usize;
+                    self.current_frame().ip += 2;
+
+                    operand
+                };
+
+                let value = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(format!("expected an object for opcode {op}")),
+                };
+
+                let matched = match op {
+                    OP_MATCH_ARRAY => match_array(&value.borrow(), operand),
+                    OP_MATCH_HASH => match_hash(&value.borrow()),
+                    _ => match &*self.constants[operand].borrow() {
+                        Object::AntString(name) => match_class(&value.borrow(), &name.value),
+                        it => return Err(format!("expected an class name, got: {}", it.inspect())),
+                    },
+                };
+
+                self.push(rc_ref_cell!(if matched { TRUE_OBJ.clone() } else { FALSE_OBJ.clone() }))?
+            }
+
+            OP_MATCH_FAIL => {
+                let value = match self.pop() {
+                    Some(it) => it.borrow().inspect(),
+                    None => String::from("none"),
+                };
+
+                return Err(format!("non-exhaustive match: no pattern matched value: {value}"));
+            }
+
+            // 解构赋值: 检查栈顶对象的结构 (不弹出)
+            OP_CHECK_ARRAY => {
+                let len = read_uint16(&instructions[(ip + 1)..]) as usize;
+                let has_rest = instructions[ip + 3] == 1;
+                self.current_frame().ip += 3;
+
+                let value = match self.stack_top() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to destructure")),
+                };
+
+                check_array(&value.borrow(), len, has_rest)?
+            }
+
+            // 弹出 n 个键, 检查其下方的字典是否包含这些键
+            OP_CHECK_HASH => {
+                let count = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                if self.sp <= count {
+                    return Err(format!("expected an object and {count} keys to destructure"));
                 }
 
-                let left_obj = left.unwrap();
-                let right_obj = right.unwrap();
+                let keys = self.stack[self.sp - count..self.sp].to_vec();
+                self.sp -= count;
 
-                if rrc_is_truthy(&left_obj) {
-                    self.push(rc_ref_cell!(native_boolean_to_object(true)))?;
-                    return Ok(());
+                let value = self.stack[self.sp - 1].clone();
+
+                check_hash(&value.borrow(), &keys)?
+            }
+
+            OP_CHECK_INSTANCE => {
+                let name_index = read_uint16(&instructions[(ip + 1)..]);
+                self.current_frame().ip += 2;
+
+                let value = match self.stack_top() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to destructure")),
+                };
+
+                match &*self.constants[name_index as usize].borrow() {
+                    Object::AntString(name) => check_instance(&value.borrow(), &name.value)?,
+                    it => return Err(format!("expected an class name, got: {}", it.inspect())),
                 }
+            }
 
-                if rrc_is_truthy(&right_obj) {
-                    self.push(rc_ref_cell!(native_boolean_to_object(true)))?;
-                    return Ok(());
+            OP_ARRAY_REST => {
+                let start = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                let value = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an array to destructure")),
+                };
+
+                let rest = array_rest(&value.borrow(), start)?;
+
+                self.push(rc_ref_cell!(rest))?
+            }
+
+            // 拼接栈顶的 n 个字符串
+            OP_BUILD_STRING => {
+                let count = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                if self.sp < count {
+                    return Err(format!("expected {count} strings to build"));
                 }
 
-                self.push(rc_ref_cell!(native_boolean_to_object(false)))?;
+                let mut s = String::new();
+
+                for obj in &self.stack[self.sp - count..self.sp] {
+                    match &*obj.borrow() {
+                        Object::AntString(it) => s.push_str(&it.value),
+                        it => s.push_str(&it.inspect()),
+                    }
+                }
+
+                self.sp -= count;
+
+                self.push(rc_ref_cell!(Object::AntString(AntString::new(s))))?
             }
 
             OP_TRUE..=OP_FALSE => {
@@ -250,16 +464,22 @@ impl<'a> Vm<'a> {
                 self.push(rc_ref_cell!(obj))?
             }
 
-            OP_MINUS..=OP_BANG => {
+            OP_MINUS..=OP_BANG | OP_BIT_NOT => {
                 let right = match self.pop() {
                     Some(obj) => obj,
                     None => return Err(format!("expected an object for opcode {}", op)),
                 };
 
+                if let Some(name) = prefix_dunder(op)
+                    && call_dunder(self, &right, name, vec![])?
+                {
+                    return Ok(());
+                }
+
                 let eval_operator_result = eval_prefix_operator(op, right.borrow().clone());
 
                 if let Err(err) = eval_operator_result {
-                    return Err(format!("error evaluating prefix operator {}: {}", op, err));
+                    return Err(self.wrap_error(format!("error evaluating prefix operator {}", prefix_operator_symbol(op)), err));
                 }
 
                 self.push(rc_ref_cell!(eval_operator_result.unwrap()))?
@@ -300,6 +520,20 @@ impl<'a> Vm<'a> {
                 }
             }
 
+            OP_TUPLE => {
+                let tuple_len = read_uint16(&instructions[(ip + 1)..]);
+                self.current_frame().ip += 2;
+
+                let items = build_array(&self.stack, self.sp - tuple_len as usize, self.sp).items;
+
+                self.sp -= tuple_len as usize;
+
+                let push_result = self.push(rc_ref_cell!(Object::AntTuple(AntTuple::from(items))));
+                if let Err(msg) = push_result {
+                    return Err(format!("error push tuple object: {msg}"));
+                }
+            }
+
             OP_HASH => {
                 let items_len = read_uint16(&instructions[(ip + 1)..]);
                 self.current_frame().ip += 2;
@@ -326,8 +560,14 @@ impl<'a> Vm<'a> {
                     ));
                 }
 
-                let left_obj = left.unwrap().borrow().clone();
-                let index_obj = index.unwrap().borrow().clone();
+                let (left, index) = (left.unwrap(), index.unwrap());
+
+                if call_dunder(self, &left, "__index__", vec![index.clone()])? {
+                    return Ok(());
+                }
+
+                let left_obj = left.borrow().clone();
+                let index_obj = index.borrow().clone();
 
                 match eval_index_expression(left_obj, index_obj) {
                     Ok(obj) => {
@@ -337,7 +577,7 @@ impl<'a> Vm<'a> {
                     }
 
                     Err(msg) => {
-                        return Err(format!("error evaluating index expression: {msg}"));
+                        return Err(self.wrap_error("error evaluating index expression", msg));
                     }
                 }
             }
@@ -346,14 +586,48 @@ impl<'a> Vm<'a> {
                 let arg_count = instructions[ip + 1];
                 self.current_frame().ip += 1;
 
-                if let Err(msg) = function_utils::call(self, arg_count as usize) {
-                    return Err(format!("error calling function: {msg}"));
+                if let Err(msg) = function_utils::call(self, arg_count as usize, &[]) {
+                    return Err(self.wrap_error("error calling function", msg));
+                }
+            }
+
+            OP_CALL_KW => {
+                let arg_count = instructions[ip + 1];
+                let names_index = read_uint16(&instructions[(ip + 2)..]);
+                self.current_frame().ip += 3;
+
+                let kw_names = match &*self.constants[names_index as usize].borrow() {
+                    Object::AntTuple(names) => names.items.iter().map(|it| it.inspect()).collect::<Vec<_>>(),
+                    it => return Err(format!("expected keyword names, got: {}", it.inspect())),
+                };
+
+                if let Err(msg) = function_utils::call(self, arg_count as usize, &kw_names) {
+                    return Err(self.wrap_error("error calling function", msg));
+                }
+            }
+
+            OP_JUMP_IF_BOUND => {
+                let local_index = read_uint16(&instructions[(ip + 1)..]);
+                let jump_to = read_uint16(&instructions[(ip + 3)..]);
+
+                let frame = self.current_frame();
+                frame.ip += 4;
+
+                let index = frame.base_pointer + local_index as usize;
+
+                // 形参已绑定实参, 跳过默认值的计算
+                if !matches!(&*self.stack[index].borrow(), Object::AntUninit(_)) {
+                    self.current_frame().ip = (jump_to as isize) - 1;
                 }
             }
 
             OP_RETURN_VALUE => {
                 let return_value = self.pop();
 
+                if let Some(value) = &return_value {
+                    self.check_return_type(&value.borrow())?;
+                }
+
                 let frame = self.pop_frame(); // 弹出当前帧
 
                 self.sp = frame.base_pointer - 1;
@@ -373,6 +647,8 @@ impl<'a> Vm<'a> {
                     return Err(format!("cannot return outside function"));
                 }
 
+                self.check_return_type(&return_value)?;
+
                 let frame = self.pop_frame(); // 弹出当前帧
 
                 self.sp = frame.base_pointer - 1;
@@ -451,6 +727,17 @@ impl<'a> Vm<'a> {
                 .borrow()
                 .clone();
 
+                let set_by_hook = call_dunder_discard(
+                    self,
+                    &target,
+                    "__setindex__",
+                    vec![index.clone(), rc_ref_cell!(value.clone())],
+                )?;
+
+                if set_by_hook {
+                    return Ok(());
+                }
+
                 eval_set_index(value, index, target)?;
             }
 
@@ -471,12 +758,35 @@ impl<'a> Vm<'a> {
                 let free_index = read_uint16(&instructions[ip + 1..]);
                 current_frame.ip += 2;
 
-                let free = current_frame.closure.free.borrow()[free_index as usize].clone();
-                if let Err(msg) = self.push(rc_ref_cell!(free)) {
+                let upvalue = current_frame.closure.free[free_index as usize].clone();
+                let free = match &*upvalue.borrow() {
+                    Upvalue::Open(index) => self.stack[*index].clone(),
+                    Upvalue::Closed(value) => value.clone(),
+                };
+
+                if let Err(msg) = self.push(free) {
                     return Err(format!("error push free variable: {msg}"));
                 }
             }
 
+            OP_SET_FREE => {
+                let current_frame = self.current_frame();
+
+                let free_index = read_uint16(&instructions[ip + 1..]);
+                current_frame.ip += 2;
+
+                let upvalue = current_frame.closure.free[free_index as usize].clone();
+                let value = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to set free")),
+                };
+
+                match &mut *upvalue.borrow_mut() {
+                    Upvalue::Open(index) => self.stack[*index] = value,
+                    Upvalue::Closed(it) => *it = value,
+                }
+            }
+
             OP_CURRENT_CLOSURE => {
                 let current_frame = self.current_frame();
 
@@ -502,12 +812,29 @@ impl<'a> Vm<'a> {
                 let symbols_len = read_uint16(&instructions[(ip + 1)..]);
                 let name_index = read_uint16(&instructions[(ip + 3)..]) as usize;
 
+                let readonly_index = read_uint16(&instructions[(ip + 5)..]) as usize;
+                let private_index = read_uint16(&instructions[(ip + 7)..]) as usize;
+                let interface = instructions[ip + 9] != 0;
+
                 let name = self.constants[name_index].borrow().inspect();
 
-                self.current_frame().ip += 4;
+                self.current_frame().ip += 9;
+
+                let mut clazz = build_class(
+                    &self.stack,
+                    &name,
+                    self.sp - symbols_len as usize,
+                    self.sp,
+                    interface,
+                )?;
 
-                let clazz =
-                    build_class(&self.stack, &name, self.sp - symbols_len as usize, self.sp)?;
+                if let Object::AntTuple(fields) = &*self.constants[readonly_index].borrow() {
+                    clazz.readonly = Rc::new(fields.items.iter().map(|it| it.inspect()).collect());
+                }
+
+                if let Object::AntTuple(fields) = &*self.constants[private_index].borrow() {
+                    clazz.private = Rc::new(fields.items.iter().map(|it| it.inspect()).collect());
+                }
 
                 self.sp -= symbols_len as usize;
 
@@ -517,10 +844,145 @@ impl<'a> Vm<'a> {
                 }
             }
 
-            OP_GET_FIELD => {
-                let field_obj_index = read_uint16(&instructions[ip + 1..]);
+            OP_ENUM_VALUE => {
+                let template_index = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                let variant = match &*self.constants[template_index].borrow() {
+                    Object::AntEnumValue(it) => it.variant.clone(),
+                    it => return Err(format!("expected an enum variant, got: {}", it.inspect())),
+                };
+
+                // 字段值按声明顺序位于栈顶
+                let start = self.sp - variant.fields.len();
+                let values = self.stack[start..self.sp]
+                    .iter()
+                    .map(|it| it.borrow().clone())
+                    .collect::<Vec<_>>();
+
+                self.sp = start;
+
+                if let Err(msg) = self.push(rc_ref_cell!(Object::AntEnumValue(AntEnumValue::new(variant, values)))) {
+                    return Err(format!("error push enum value: {msg}"));
+                }
+            }
+
+            OP_ENUM => {
+                let variants_index = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                let variants = match &*self.constants[variants_index].borrow() {
+                    Object::AntTuple(templates) => templates
+                        .items
+                        .iter()
+                        .filter_map(|it| match it {
+                            Object::AntEnumValue(it) => Some(it.variant.clone()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>(),
+                    it => return Err(format!("expected enum variants, got: {}", it.inspect())),
+                };
+
+                // 栈顶为 OpClass 构造的枚举类
+                match &mut *self.stack[self.sp - 1].borrow_mut() {
+                    Object::AntClass(clazz) => clazz.variants = Some(Rc::new(variants)),
+                    it => return Err(format!("expected an enum class, got: {}", it.inspect())),
+                }
+            }
+
+            OP_MATCH_VARIANT => {
+                let name_index = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                let (enumeration, value) = match (self.pop(), self.pop()) {
+                    (Some(enumeration), Some(value)) => (enumeration, value),
+                    _ => return Err(format!("expected an object for opcode {op}")),
+                };
+
+                let name = self.constants[name_index].borrow().inspect();
+                let matched = match_variant(&value.borrow(), &enumeration.borrow(), &name)?;
+
+                self.push(rc_ref_cell!(if matched { TRUE_OBJ.clone() } else { FALSE_OBJ.clone() }))?
+            }
+
+            OP_INHERIT => {
+                let base = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an class to inherit")),
+                };
+
+                if !matches!(&*base.borrow(), Object::AntClass(_)) {
+                    return Err(format!(
+                        "expected an class to inherit, got: {}",
+                        base.borrow().inspect()
+                    ));
+                }
+
+                let clazz = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an class to set base")),
+                };
+
+                if let Object::AntClass(clazz) = &mut *clazz.borrow_mut() {
+                    clazz.base = Some(base);
+
+                    // 继承接口的类需要定义接口中未定义的成员
+                    check_members_defined(clazz)?;
+                }
+
+                if let Err(msg) = self.push(clazz) {
+                    return Err(format!("error push class object: {msg}"));
+                }
+            }
+
+            OP_GET_SUPER => {
+                let field_index = read_uint16(&instructions[ip + 1..]);
                 self.current_frame().ip += 2;
 
+                let field = self.field_pool[field_index as usize].clone();
+
+                let me = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to bind super")),
+                };
+
+                let base = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an parent class")),
+                };
+
+                let value = match &*base.borrow() {
+                    Object::AntClass(clazz) => match clazz.get_member(&field) {
+                        Some(Object::Method(mut method)) => {
+                            method.me = Some(me);
+
+                            Object::Method(method)
+                        }
+                        Some(it) => it,
+                        None => {
+                            return Err(format!(
+                                "parent class '{}' has no field '{field}'",
+                                clazz.name
+                            ));
+                        }
+                    },
+                    it => {
+                        return Err(format!(
+                            "expected an parent class, got: {}",
+                            it.inspect()
+                        ));
+                    }
+                };
+
+                if let Err(msg) = self.push(rc_ref_cell!(value)) {
+                    return Err(format!("error push super member: {msg}"));
+                }
+            }
+
+            OP_GET_FIELD | OP_GET_SELF_FIELD => {
+                let field_obj_index = read_uint16(&instructions[ip + 1..]);
+                let class_name = self.read_field_access_class(op, ip, &instructions);
+
                 let field = self.field_pool[field_obj_index as usize].clone();
 
                 let obj = match self.pop() {
@@ -528,13 +990,52 @@ impl<'a> Vm<'a> {
                     None => Err(format!("expected an object to get field"))?,
                 };
 
+                check_field_access(&obj.borrow(), &field, class_name.as_deref())?;
+
                 return eval_obj_member(self, obj, field);
             }
 
-            OP_SET_FIELD => {
+            OP_GET_CLASS_MEMBER => {
                 let field_index = read_uint16(&instructions[ip + 1..]);
                 self.current_frame().ip += 2;
 
+                let field = self.field_pool[field_index as usize].clone();
+
+                let obj = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an class to get member")),
+                };
+
+                check_field_access(&obj.borrow(), &field, None)?;
+
+                // 与 '.' 不同, '::' 不绑定 self
+                let member = match &*obj.borrow() {
+                    Object::AntClass(clazz) => match clazz.get_member(&field) {
+                        Some(it) => it,
+                        None => {
+                            return Err(format!(
+                                "class '{}' has no member '{field}'",
+                                clazz.name
+                            ));
+                        }
+                    },
+                    it => {
+                        return Err(format!(
+                            "expected an class to get member, got: {}",
+                            it.inspect()
+                        ));
+                    }
+                };
+
+                if let Err(msg) = self.push(rc_ref_cell!(member)) {
+                    return Err(format!("error push class member: {msg}"));
+                }
+            }
+
+            OP_SET_FIELD | OP_SET_SELF_FIELD => {
+                let field_index = read_uint16(&instructions[ip + 1..]);
+                let class_name = self.read_field_access_class(op, ip, &instructions);
+
                 let target = match self.pop() {
                     Some(it) => it,
                     None => return Err(format!("expected an object to set field value")),
@@ -549,6 +1050,29 @@ impl<'a> Vm<'a> {
                 .borrow()
                 .clone();
 
+                check_field_access(&target.borrow(), &ident, class_name.as_deref())?;
+
+                if let Object::AntClass(clazz) = &*target.borrow()
+                    && clazz.is_readonly(&ident)
+                {
+                    return Err(format!("cannot assign to readonly field: {ident}"));
+                }
+
+                // 类外部的赋值交给 __set__, 类中方法通过 self 赋值时直接写入
+                if class_name.is_none()
+                    && call_dunder_discard(
+                        self,
+                        &target,
+                        "__set__",
+                        vec![
+                            rc_ref_cell!(Object::AntString(AntString::new(ident.clone()))),
+                            rc_ref_cell!(value.clone()),
+                        ],
+                    )?
+                {
+                    return Ok(());
+                }
+
                 let mut target_borrow = target.borrow_mut();
 
                 match &mut *target_borrow {
@@ -601,6 +1125,112 @@ impl<'a> Vm<'a> {
                 }
             }
 
+            OP_GET_ITER => {
+                let obj = match self.pop() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an object to iterate")),
+                };
+
+                let iter = eval_get_iter(obj.borrow().clone())?;
+
+                if let Err(msg) = self.push(rc_ref_cell!(iter)) {
+                    return Err(format!("error push iterator: {msg}"));
+                }
+            }
+
+            OP_ITER_NEXT => {
+                let jump_to = read_uint16(&instructions[(ip + 1)..]);
+
+                // 迭代器留在栈上, 由循环结束处的 OpPop 弹出
+                let iter = match self.stack_top() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an iterator")),
+                };
+
+                match eval_iter_next(self, iter)? {
+                    Some(item) => {
+                        self.current_frame().ip += 2;
+
+                        if let Err(msg) = self.push(item) {
+                            return Err(format!("error push iterator item: {msg}"));
+                        }
+                    }
+
+                    None => self.current_frame().ip = (jump_to as isize) - 1,
+                }
+            }
+
+            OP_SETUP_TRY => {
+                let catch_ip = read_uint16(&instructions[(ip + 1)..]) as usize;
+                self.current_frame().ip += 2;
+
+                let sp = self.sp;
+                self.current_frame()
+                    .handlers
+                    .push(ExceptionHandler { catch_ip, sp });
+            }
+
+            OP_POP_TRY => {
+                self.current_frame().handlers.pop();
+            }
+
+            OP_YIELD => {
+                if let Err(msg) = suspend_generator(self) {
+                    return Err(format!("error yield value: {msg}"));
+                }
+            }
+
+            OP_THROW => {
+                let err = match self.pop() {
+                    Some(it) => it.borrow().clone(),
+                    None => return Err(String::from("expected an error to throw")),
+                };
+
+                let msg = match &err {
+                    Object::AntError(e) => e.message.clone(),
+                    it => it.inspect(),
+                };
+
+                self.thrown = Some(err);
+
+                return Err(msg);
+            }
+
+            OP_IMPORT_MEMBER => {
+                let field_index = read_uint16(&instructions[ip + 1..]);
+                self.current_frame().ip += 2;
+
+                let field = &self.field_pool[field_index as usize];
+
+                // 模块留在栈上, 由编译器在导入结束后弹出
+                let module = match self.stack_top() {
+                    Some(it) => it,
+                    None => return Err(String::from("expected an module to import from")),
+                };
+
+                let member = match &*module.borrow() {
+                    Object::AntClass(clazz) => match clazz.map.get(field) {
+                        Some(it) => it.clone(),
+                        None => {
+                            return Err(format!(
+                                "cannot import name '{field}' from module '{}'",
+                                clazz.name
+                            ));
+                        }
+                    },
+                    it => {
+                        return Err(format!(
+                            "expected an module to import from, got: {}",
+                            it.inspect()
+                        ));
+                    }
+                };
+
+                if let Err(msg) = self.push(rc_ref_cell!(member)) {
+                    return Err(format!("error push imported member: {msg}"));
+                }
+            }
+
             OP_NONE => {
                 if let Err(msg) = self.push(rc_ref_cell!(NONE_OBJ.clone())) {
                     return Err(format!("error push none object: {msg}"));
@@ -646,12 +1276,78 @@ impl<'a> Vm<'a> {
 
             op = instructions[ip];
 
-            self.next(op, ip, instructions)?;
+            if let Err(msg) = self.next(op, ip, instructions) {
+                self.handle_error(msg, 0)?;
+            }
         }
 
         Ok(())
     }
 
+    /// 寻找 min_frame_index 及以上的栈帧中最近的异常处理器
+    /// 找到则展开栈帧, 将错误对象压栈并跳转到 catch 处; 否则原样返回错误
+    pub fn handle_error(&mut self, msg: String, min_frame_index: usize) -> Result<(), String> {
+        let handler_frame_index = (min_frame_index..self.frame_index)
+            .rev()
+            .find(|i| !self.frames[*i].handlers.is_empty());
+
+        let frame_index = match handler_frame_index {
+            Some(it) => it,
+            None => {
+                // 错误离开了整个程序
+                if min_frame_index == 0 {
+                    self.thrown = None;
+                    self.error_message = None;
+                }
+
+                return Err(msg);
+            }
+        };
+
+        // 错误在此被捕获, 取走记录的信息, 以免留给之后的错误
+        let message = self.error_message.take();
+
+        let err = match self.thrown.take() {
+            Some(it) => it,
+            None => Object::AntError(AntError::new(
+                "RuntimeError".into(),
+                message.unwrap_or_else(|| msg.clone()),
+                format!("{}\n{msg}", self.traceback_string()),
+            )),
+        };
+
+        self.frame_index = frame_index + 1;
+
+        let handler = self.frames[frame_index]
+            .handlers
+            .pop()
+            .expect("expected an exception handler");
+
+        // 被展开的栈帧中被捕获的局部变量需要关闭
+        self.close_upvalues(handler.sp);
+
+        self.sp = handler.sp;
+        self.current_frame().ip = handler.catch_ip as isize - 1;
+
+        if let Err(msg) = self.push(rc_ref_cell!(err)) {
+            return Err(format!("error push error object: {msg}"));
+        }
+
+        Ok(())
+    }
+
+    /// 给错误信息加上前缀, 并记录最初的错误信息
+    /// 前缀只出现在回溯中, 捕获到的错误对象的 message 为最初的信息
+    pub fn wrap_error(&mut self, context: impl std::fmt::Display, msg: String) -> String {
+        let wrapped = format!("{context}: {msg}");
+
+        if self.error_message.is_none() {
+            self.error_message = Some(msg);
+        }
+
+        wrapped
+    }
+
     pub fn stack_top(&self) -> Option<Rc<RefCell<Object>>> {
         if self.sp == 0 {
             None
@@ -664,6 +1360,18 @@ impl<'a> Vm<'a> {
         self.stack.get(self.sp).cloned()
     }
 
+    // 按当前函数的返回类型注解检查返回值
+    fn check_return_type(&mut self, value: &Object) -> Result<(), String> {
+        let func = &self.current_frame().closure.func;
+
+        match &func.return_type {
+            Some(hint) if !func.unchecked => check_type_hint(value, hint, || {
+                format!("return value of {}", func.scope_info.scope_name)
+            }),
+            _ => Ok(()),
+        }
+    }
+
     #[inline(always)]
     pub fn push(&mut self, obj: Rc<RefCell<Object>>) -> Result<(), String> {
         if self.sp >= STACK_SIZE {
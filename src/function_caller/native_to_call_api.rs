@@ -2,16 +2,20 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     byte_code_vm::{code::code::{OP_RETURN, OP_RETURN_VALUE}, constants::NONE_OBJ, vm::{
+        eval_functions::eval_dunder::bind_dunder,
         function_utils::{call_closure, call_method, call_native},
         vm::Vm,
     }},
     obj_enum::object::Object,
-    object::object::IAntObject, rc_ref_cell,
+    object::{ant_method::{Method, MethodType}, object::IAntObject}, rc_ref_cell,
 };
 
 fn next(
     vm: &mut Vm
 ) -> Result<(), String> {
+    // 只处理本次调用产生的栈帧中的异常处理器
+    let min_frame_index = vm.frame_index - 1;
+
     while {
         let instructions = vm.current_frame().instructions();
 
@@ -64,7 +68,9 @@ fn next(
             }
 
             _ => {
-                vm.next(op, ip, instructions)?;
+                if let Err(msg) = vm.next(op, ip, instructions) {
+                    vm.handle_error(msg, min_frame_index)?;
+                }
             },
         }
     }
@@ -87,7 +93,14 @@ fn native_to_call_closure(
     }
 
     // 调用闭包，这会创建新的栈帧
-    call_closure(vm, cl, args.len())?;
+    let frame_index = vm.frame_index;
+
+    call_closure(vm, cl, args.len(), &[])?;
+
+    // 生成器函数不会创建新的栈帧, 生成器已经在栈上了
+    if vm.frame_index == frame_index {
+        return Ok(());
+    }
 
     next(vm)
 }
@@ -98,13 +111,25 @@ fn native_to_call_method(
     method: Rc<RefCell<Object>>,
     args: Vec<Rc<RefCell<Object>>>,
 ) -> Result<(), String> {
+    let is_native = matches!(
+        &*method.borrow(),
+        Object::Method(Method { func: MethodType::NativeFunction(_), .. })
+    );
+
     vm.push(method.clone())?;
 
     for arg in args.iter() {
         vm.push(arg.clone())?;
     }
 
-    call_method(vm, method, args.len())?;
+    let frame_index = vm.frame_index;
+
+    call_method(vm, method, args.len(), &[])?;
+
+    // 原生方法与生成器方法不会创建新的栈帧, 返回值已经在栈上了
+    if is_native || vm.frame_index == frame_index {
+        return Ok(());
+    }
 
     next(vm)
 }
@@ -139,6 +164,11 @@ pub fn native_to_call(
         Object::Closure(_cl) => CallType::Closure,
         Object::AntNativeFunction(_native) => CallType::Native,
         Object::Method(_method) => CallType::Method,
+        // 可调用对象: 调用其 __call__ 方法
+        Object::AntClass(_) => match bind_dunder(&func, "__call__") {
+            Some(method) => return native_to_call_method(vm, rc_ref_cell!(Object::Method(method)), args),
+            None => return Err(format!("calling non-function: {}", func.borrow().inspect())),
+        },
         _ => return Err(format!("calling non-function: {}", func.borrow().inspect())),
     };
 
@@ -0,0 +1,171 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatStringPiece {
+    Literal(String),
+    Expression { source: String, spec: Option<String> }, // {source:spec}
+}
+
+/// 简单转义字符 (\n, \t ...) 对应的字符
+pub fn escape_char(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '\\' => Some('\\'),
+        '"' => Some('"'),
+        '0' => Some('\0'),
+        'b' => Some('\u{0008}'), // backspace
+        'f' => Some('\u{000C}'), // form feed
+        _ => None,
+    }
+}
+
+/// 扫描格式化字符串 (起始双引号之后) 直到结束双引号,
+/// 返回切分好的片段与字符串体的长度 (不含结束双引号)
+pub fn scan_format_string(chars: &[char]) -> Result<(Vec<FormatStringPiece>, usize), String> {
+    let mut pieces = vec![];
+    let mut literal = String::new();
+
+    let mut i = 0;
+
+    loop {
+        let c = match chars.get(i) {
+            Some(it) => *it,
+            None => return Err(String::from("unclosed format string")),
+        };
+
+        match c {
+            '"' => break,
+
+            '\\' => {
+                i += 1;
+
+                let escaped = match chars.get(i) {
+                    Some(it) => *it,
+                    None => return Err(String::from("unclosed format string")),
+                };
+
+                if escaped == 'u' && chars.get(i + 1) == Some(&'{') {
+                    // Unicode转义: \u{XXXX}
+                    let start = i + 2;
+                    let end = match chars[start..].iter().position(|it| *it == '}') {
+                        Some(it) => start + it,
+                        None => return Err(String::from("invalid unicode escape sequence")),
+                    };
+
+                    let hex_digits = chars[start..end].iter().collect::<String>();
+
+                    match u32::from_str_radix(&hex_digits, 16).ok().and_then(char::from_u32) {
+                        Some(ch) => literal.push(ch),
+                        None => return Err(String::from("invalid unicode escape sequence")),
+                    }
+
+                    i = end;
+                } else if let Some(ch) = escape_char(escaped) {
+                    literal.push(ch);
+                } else {
+                    // 未知的转义序列，原样输出
+                    literal.push('\\');
+                    literal.push(escaped);
+                }
+            }
+
+            '{' if chars.get(i + 1) == Some(&'{') => {
+                literal.push('{');
+                i += 1;
+            }
+
+            '}' if chars.get(i + 1) == Some(&'}') => {
+                literal.push('}');
+                i += 1;
+            }
+
+            '}' => return Err(String::from("single '}' is not allowed in format string")),
+
+            '{' => {
+                if !literal.is_empty() {
+                    pieces.push(FormatStringPiece::Literal(std::mem::take(&mut literal)));
+                }
+
+                i += 1;
+
+                let (piece, end) = scan_embedded_expression(chars, i)?;
+                pieces.push(piece);
+
+                i = end;
+            }
+
+            _ => literal.push(c),
+        }
+
+        i += 1;
+    }
+
+    if !literal.is_empty() {
+        pieces.push(FormatStringPiece::Literal(literal));
+    }
+
+    Ok((pieces, i))
+}
+
+// 扫描 '{' 之后的嵌入表达式, 返回表达式片段与结束 '}' 的位置
+fn scan_embedded_expression(
+    chars: &[char],
+    start: usize,
+) -> Result<(FormatStringPiece, usize), String> {
+    let mut depth = 0;
+    let mut spec_start = None;
+
+    let mut i = start;
+
+    loop {
+        let c = match chars.get(i) {
+            Some(it) => *it,
+            None => return Err(String::from("expected '}' in format string")),
+        };
+
+        match c {
+            // 跳过表达式中的字符串
+            '"' if spec_start.is_none() => {
+                i += 1;
+
+                loop {
+                    match chars.get(i) {
+                        Some('"') => break,
+                        Some('\\') => i += 2,
+                        Some(_) => i += 1,
+                        None => return Err(String::from("unclosed string in format string")),
+                    }
+                }
+            }
+
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' => break,
+
+            // 顶层的 ':' 为格式说明符的开始 ('::' 除外)
+            ':' if depth == 0 && spec_start.is_none() => {
+                if chars.get(i + 1) == Some(&':') {
+                    i += 1;
+                } else {
+                    spec_start = Some(i);
+                }
+            }
+
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    let expr_end = spec_start.unwrap_or(i);
+
+    let source = chars[start..expr_end].iter().collect::<String>();
+    if source.trim().is_empty() {
+        return Err(String::from("empty expression in format string"));
+    }
+
+    let spec = spec_start.map(|it| chars[it + 1..i].iter().collect::<String>());
+
+    Ok((FormatStringPiece::Expression { source, spec }, i))
+}
@@ -1,6 +1,7 @@
 use unicode_properties::UnicodeEmoji;
 
 use crate::constants::*;
+use crate::lexer::format_string::{escape_char, scan_format_string};
 use crate::token::token::Token;
 use crate::token::token_type::{TOKEN_TYPE_MAP, TokenNumType, TokenType};
 
@@ -14,6 +15,7 @@ pub struct Lexer {
     line: usize,
     column: usize,
     code_vec: Vec<char>,
+    last_token: Option<TokenType>, // 上一个词法单元的类型
 }
 
 impl Lexer {
@@ -28,6 +30,7 @@ impl Lexer {
             line: 1,
             column: 1,
             code_vec: vec![],
+            last_token: None,
         };
 
         lexer.code_vec = lexer.code.chars().collect();
@@ -61,17 +64,18 @@ impl Lexer {
     }
 
     fn read_char(&mut self) -> char {
+        // 换行符属于它所结束的行, 离开换行符时才进入下一行
+        if self.cur_char == NEW_LINE {
+            self.line += 1;
+            self.column = 1;
+        }
+
         if self.next_pos < self.code_vec.len() {
             self.cur_char = self.code_vec[self.next_pos]
         } else {
             self.cur_char = NULL_CHAR;
         }
 
-        if self.cur_char == NEW_LINE {
-            self.line += 1;
-            self.column = 0;
-        }
-
         self.column += 1;
 
         self.pos = self.next_pos;
@@ -80,10 +84,10 @@ impl Lexer {
         self.cur_char
     }
 
+    // 换行符不是空白, 会被读取为 Eol 词法单元
     fn skip_whitespace(&mut self) {
         while self.cur_char == ' '
             || self.cur_char == '\t'
-            || self.cur_char == '\n'
             || self.cur_char == '\r'
         {
             self.read_char();
@@ -155,14 +159,6 @@ impl Lexer {
                 // 处理转义字符
                 self.read_char();
                 match self.cur_char {
-                    'n' => result.push('\n'),
-                    't' => result.push('\t'),
-                    'r' => result.push('\r'),
-                    '\\' => result.push('\\'),
-                    '"' => result.push('"'),
-                    '0' => result.push('\0'),
-                    'b' => result.push('\u{0008}'), // backspace
-                    'f' => result.push('\u{000C}'), // form feed
                     'u' => {
                         // Unicode转义: \u{XXXX}
                         if self.peek_char() == '{' {
@@ -211,11 +207,14 @@ impl Lexer {
                             return "".to_string();
                         }
                     }
-                    _ => {
-                        // 未知的转义序列，原样输出
-                        result.push('\\');
-                        result.push(self.cur_char);
-                    }
+                    c => match escape_char(c) {
+                        Some(ch) => result.push(ch),
+                        None => {
+                            // 未知的转义序列，原样输出
+                            result.push('\\');
+                            result.push(c);
+                        }
+                    },
                 }
             } else {
                 // 普通字符，直接添加
@@ -263,6 +262,19 @@ impl Lexer {
         }
 
         match self.cur_char {
+            // 连续的换行 (及其间的空白) 只产生一个 Eol
+            // 注释在 '/' 分支中被跳过后会回到这里, 若上一个词法单元已是 Eol 则不再产生新的 Eol
+            NEW_LINE => {
+                while matches!(self.peek_char(), NEW_LINE | ' ' | '\t' | '\r') {
+                    self.read_char();
+                }
+
+                if matches!(self.last_token, Some(TokenType::Eol)) {
+                    self.read_char();
+                    return self.next_token();
+                }
+            }
+
             '=' => {
                 let peek_char = self.peek_char();
 
@@ -270,6 +282,11 @@ impl Lexer {
                     token.token_type = TokenType::Eq;
                     token.value = format!("{}{}", self.cur_char, peek_char);
 
+                    self.read_char();
+                } else if peek_char == '>' {
+                    token.token_type = TokenType::FatArrow;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
                     self.read_char();
                 }
             }
@@ -294,6 +311,16 @@ impl Lexer {
                 }
             }
 
+            '.' => {
+                if self.peek_char() == '.' && self.get_char(self.next_pos + 1) == '.' {
+                    token.token_type = TokenType::Ellipsis;
+                    token.value = String::from("...");
+
+                    self.read_char();
+                    self.read_char();
+                }
+            }
+
             '"' => {
                 if self.cur_char == '"' {
                     let s = self.read_string();
@@ -304,6 +331,69 @@ impl Lexer {
                 }
             }
 
+            '<' => {
+                let peek_char = self.peek_char();
+                if peek_char == '<' {
+                    token.token_type = TokenType::ShiftLeft;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
+                    self.read_char();
+                    self.read_compound_assign(&mut token, TokenType::ShiftLeftAssign);
+                } else if peek_char == '=' {
+                    token.token_type = TokenType::LtEq;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
+                    self.read_char();
+                }
+            }
+
+            '>' => {
+                let peek_char = self.peek_char();
+                if peek_char == '>' {
+                    token.token_type = TokenType::ShiftRight;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
+                    self.read_char();
+                    self.read_compound_assign(&mut token, TokenType::ShiftRightAssign);
+                } else if peek_char == '=' {
+                    token.token_type = TokenType::GtEq;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
+                    self.read_char();
+                }
+            }
+
+            '*' => {
+                let peek_char = self.peek_char();
+                if peek_char == '*' {
+                    token.token_type = TokenType::Power;
+                    token.value = format!("{}{}", self.cur_char, peek_char);
+
+                    self.read_char();
+                    self.read_compound_assign(&mut token, TokenType::PowerAssign);
+                } else {
+                    self.read_compound_assign(&mut token, TokenType::AsteriskAssign);
+                }
+            }
+
+            '+' => self.read_compound_assign(&mut token, TokenType::PlusAssign),
+            '-' => self.read_compound_assign(&mut token, TokenType::MinusAssign),
+            '%' => self.read_compound_assign(&mut token, TokenType::PercentAssign),
+            '&' => self.read_compound_assign(&mut token, TokenType::AmpersandAssign),
+            '|' => self.read_compound_assign(&mut token, TokenType::PipeAssign),
+            '^' => self.read_compound_assign(&mut token, TokenType::CaretAssign),
+
+            '~' => {
+                // '~/' 为整除运算符, 单独的 '~' 为按位取反
+                if self.peek_char() == '/' {
+                    token.token_type = TokenType::FloorDiv;
+                    token.value = String::from("~/");
+
+                    self.read_char();
+                    self.read_compound_assign(&mut token, TokenType::FloorDivAssign);
+                }
+            }
+
             '/' => {
                 let peek_char = self.peek_char();
                 if peek_char == '/' {
@@ -311,9 +401,40 @@ impl Lexer {
                     self.read_comment();
                     // 递归调用 next_token 跳过注释，获取下一个有效token
                     return self.next_token();
+                } else {
+                    self.read_compound_assign(&mut token, TokenType::SlashAssign);
                 }
             }
 
+            'f' if self.peek_char() == '"' => {
+                // 格式化字符串: f"...{expr:spec}..."
+                match scan_format_string(&self.code_vec[self.next_pos + 1..]) {
+                    Ok((_, len)) => {
+                        token.token_type = TokenType::FormatString;
+                        token.value = self.code_vec[self.next_pos + 1..self.next_pos + 1 + len]
+                            .iter()
+                            .collect();
+
+                        // 跳过 f, 双引号与字符串体
+                        for _ in 0..len + 3 {
+                            self.read_char();
+                        }
+                    }
+
+                    Err(msg) => {
+                        self.errors.push(format!(
+                            "{msg}. at file: <{}>, line {}, column {}",
+                            self.file, token.line, token.column
+                        ));
+
+                        self.read_char(); // 跳过 f
+                        self.read_string();
+                    }
+                }
+
+                return token;
+            }
+
             _ => {
                 if self.is_valid_char(self.cur_char) && !self.cur_char.is_ascii_digit() {
                     let ident = self.read_ident();
@@ -345,6 +466,16 @@ impl Lexer {
         token
     }
 
+    // 若运算符后紧跟 '=', 则将其读取为复合赋值运算符
+    fn read_compound_assign(&mut self, token: &mut Token, assign_type: TokenType) {
+        if self.peek_char() == '=' {
+            token.token_type = assign_type;
+            token.value.push('=');
+
+            self.read_char();
+        }
+    }
+
     fn eof(&self) -> bool {
         self.cur_char == NULL_CHAR
     }
@@ -353,7 +484,11 @@ impl Lexer {
         let mut tokens = Vec::new();
 
         while !self.eof() {
-            tokens.push(self.next_token());
+            let token = self.next_token();
+
+            self.last_token = Some(token.token_type);
+
+            tokens.push(token);
         }
 
         tokens
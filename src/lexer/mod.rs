@@ -1,2 +1,3 @@
+pub mod format_string;
 pub mod lexer;
 mod test;
@@ -115,6 +115,7 @@ fn test_lexer_comments() {
         TokenType::Assign,
         TokenType::IntegerBig,
         TokenType::Semicolon,
+        TokenType::Eol,
         TokenType::Let,
         TokenType::Ident,
         TokenType::Assign,
@@ -236,3 +237,208 @@ fn test_lexer_string_invalid_escapes() {
         "Lexer should report error for invalid escape sequences"
     );
 }
+
+#[test]
+fn test_lexer_arithmetic_operators() {
+    use super::lexer::Lexer;
+
+    use crate::token::token_type::TokenType;
+    use crate::utils::assert_eq;
+
+    // '~/' 为整除, '//' 为注释
+    let code = "a ~/ 2 % 3 ** 2\n// comment\nb";
+    let mut lexer = Lexer::new(code.to_string(), "__test_lexer_arithmetic_operators__".into());
+
+    let tokens = lexer.get_tokens();
+
+    let expected_token_types = vec![
+        TokenType::Ident,
+        TokenType::FloorDiv,
+        TokenType::IntegerBig,
+        TokenType::Percent,
+        TokenType::IntegerBig,
+        TokenType::Power,
+        TokenType::IntegerBig,
+        TokenType::Eol,
+        TokenType::Ident,
+    ];
+
+    let token_types = tokens.iter().map(|it| it.token_type).collect::<Vec<_>>();
+
+    assert_eq(&token_types, &expected_token_types, || {
+        println!("Expected: {:?}", expected_token_types);
+        println!("Got: {:?}", token_types);
+    });
+}
+
+#[test]
+fn test_lexer_trailing_comments() {
+    use super::lexer::Lexer;
+
+    use crate::token::token_type::TokenType;
+
+    // '//' 始终为注释, 整除使用 '~/'
+    let tests = vec![
+        ("a ~/ 2", vec![TokenType::Ident, TokenType::FloorDiv, TokenType::IntegerBig]),
+        ("a ~/= 2", vec![TokenType::Ident, TokenType::FloorDivAssign, TokenType::IntegerBig]),
+        ("~a", vec![TokenType::Tilde, TokenType::Ident]),
+        ("a // 2", vec![TokenType::Ident]),
+        ("let y = x // half", vec![TokenType::Let, TokenType::Ident, TokenType::Assign, TokenType::Ident]),
+        ("a // (b)", vec![TokenType::Ident]),
+        ("let x = 1 // some note", vec![TokenType::Let, TokenType::Ident, TokenType::Assign, TokenType::IntegerBig]),
+        ("let x = 1 // 注释", vec![TokenType::Let, TokenType::Ident, TokenType::Assign, TokenType::IntegerBig]),
+        ("f(x) // TODO: y", vec![TokenType::Ident, TokenType::LParen, TokenType::Ident, TokenType::RParen]),
+        ("x // ", vec![TokenType::Ident]),
+    ];
+
+    for (code, expected) in tests {
+        let mut lexer = Lexer::new(code.to_string(), "__test_lexer_trailing_comments__".into());
+
+        let token_types = lexer
+            .get_tokens()
+            .iter()
+            .map(|it| it.token_type)
+            .filter(|it| *it != TokenType::Eof)
+            .collect::<Vec<_>>();
+
+        assert_eq!(token_types, expected, "{code}");
+    }
+}
+
+#[test]
+fn test_lexer_bitwise_operators() {
+    use super::lexer::Lexer;
+
+    use crate::token::token_type::TokenType;
+    use crate::utils::assert_eq;
+
+    let code = "a & b | c ^ ~d << 1 >> 2 < 3";
+    let mut lexer = Lexer::new(code.to_string(), "__test_lexer_bitwise_operators__".into());
+
+    let tokens = lexer.get_tokens();
+
+    let expected_token_types = vec![
+        TokenType::Ident,
+        TokenType::Ampersand,
+        TokenType::Ident,
+        TokenType::Pipe,
+        TokenType::Ident,
+        TokenType::Caret,
+        TokenType::Tilde,
+        TokenType::Ident,
+        TokenType::ShiftLeft,
+        TokenType::IntegerBig,
+        TokenType::ShiftRight,
+        TokenType::IntegerBig,
+        TokenType::Lt,
+        TokenType::IntegerBig,
+    ];
+
+    let token_types = tokens.iter().map(|it| it.token_type).collect::<Vec<_>>();
+
+    assert_eq(&token_types, &expected_token_types, || {
+        println!("Expected: {:?}", expected_token_types);
+        println!("Got: {:?}", token_types);
+    });
+}
+
+#[test]
+fn test_lexer_compound_assign() {
+    use super::lexer::Lexer;
+
+    use crate::token::token_type::TokenType;
+    use crate::utils::assert_eq;
+
+    let code = "a += 1 a -= 1 a *= 1 a /= 1 a %= 1 a ~/= 1 a **= 1 a &= 1 a |= 1 a ^= 1 a <<= 1 a >>= 1";
+    let mut lexer = Lexer::new(code.to_string(), "__test_lexer_compound_assign__".into());
+
+    let tokens = lexer.get_tokens();
+
+    let expected_token_types = vec![
+        TokenType::PlusAssign,
+        TokenType::MinusAssign,
+        TokenType::AsteriskAssign,
+        TokenType::SlashAssign,
+        TokenType::PercentAssign,
+        TokenType::FloorDivAssign,
+        TokenType::PowerAssign,
+        TokenType::AmpersandAssign,
+        TokenType::PipeAssign,
+        TokenType::CaretAssign,
+        TokenType::ShiftLeftAssign,
+        TokenType::ShiftRightAssign,
+    ];
+
+    // 每组为: 标识符, 复合赋值运算符, 数字
+    let token_types = tokens
+        .iter()
+        .skip(1)
+        .step_by(3)
+        .map(|it| it.token_type)
+        .collect::<Vec<_>>();
+
+    assert_eq(&token_types, &expected_token_types, || {
+        println!("Expected: {:?}", expected_token_types);
+        println!("Got: {:?}", token_types);
+    });
+}
+
+#[test]
+fn test_lexer_format_string() {
+    use super::lexer::Lexer;
+
+    use crate::token::token_type::TokenType;
+    use crate::utils::assert_eq;
+
+    let code = r#"f"a {b + "}"} {{c}}" f + "d""#;
+    let mut lexer = Lexer::new(code.to_string(), "__test_lexer_format_string__".into());
+
+    let tokens = lexer.get_tokens();
+
+    let token_types = tokens.iter().map(|it| it.token_type).collect::<Vec<_>>();
+    let expected_token_types = vec![
+        TokenType::FormatString,
+        TokenType::Ident,
+        TokenType::Plus,
+        TokenType::String,
+    ];
+
+    assert_eq(&token_types, &expected_token_types, || {
+        println!("Expected: {:?}", expected_token_types);
+        println!("Got: {:?}", token_types);
+    });
+
+    // 词法单元的值为原始字符串体
+    assert_eq!(tokens[0].value, r#"a {b + "}"} {{c}}"#);
+}
+
+#[test]
+fn test_lexer_newline_lines() {
+    use super::lexer::Lexer;
+    use crate::token::token_type::TokenType;
+
+    // Eol 位于它所结束的行, 连续的换行与注释行只产生一个 Eol
+    let mut l = Lexer::new(
+        "let a = 1\n\n// 注释\n  a".to_string(),
+        String::from("__test_lexer_newline_lines__"),
+    );
+    let tokens = l.get_tokens();
+
+    let actual = tokens
+        .iter()
+        .map(|it| (it.token_type, it.line, it.column))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        actual,
+        vec![
+            (TokenType::Let, 1, 1),
+            (TokenType::Ident, 1, 5),
+            (TokenType::Assign, 1, 7),
+            (TokenType::IntegerBig, 1, 9),
+            (TokenType::Eol, 1, 10),
+            (TokenType::Ident, 4, 3),
+        ],
+        "{tokens:#?}"
+    );
+}
@@ -5,12 +5,15 @@ use std::hash::Hasher;
 use enum_dispatch::enum_dispatch;
 
 use crate::object::ant_array::AntArray;
+use crate::object::ant_tuple::AntTuple;
 use crate::object::ant_hash_map::AntHashMap;
 use crate::object::ant_boolean::AntBoolean;
 use crate::object::ant_class::AntClass;
 use crate::object::ant_closure::Closure;
+use crate::object::ant_generator::AntGenerator;
 use crate::object::ant_compiled_function::CompiledFunction;
 use crate::object::ant_double::AntDouble;
+use crate::object::ant_enum_value::AntEnumValue;
 use crate::object::ant_error::AntError;
 use crate::object::ant_i64::AntI64;
 use crate::object::ant_int::AntInt;
@@ -28,10 +31,13 @@ use crate::object::object::IAntObject;
 #[derive(Debug, Clone)]
 pub enum Object {
     AntArray,
+    AntTuple,
     AntHashMap,
     AntBoolean,
     AntClass,
+    AntEnumValue,
     Closure,
+    AntGenerator,
     CompiledFunction,
     Method,
     AntDouble,
@@ -52,11 +58,15 @@ impl AsAnyMut for Object {
 
 impl Hash for Object {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        // 哈希必须与 equals 保持一致: 按值比较的类型按值哈希
         match self {
             Object::AntInt(ant_int) => {
-                // 使用AntInt特定的哈希逻辑
+                // 使用AntInt特定的哈希逻辑 (与相等的 AntDouble 哈希相同)
                 ant_int.value.hash(state);
             },
+            Object::AntDouble(ant_double) => {
+                ant_double.value.hash(state);
+            },
             Object::AntI64(ant_i64) => {
                 // 使用AntI64特定的哈希逻辑
                 ant_i64.value.hash(state);
@@ -65,7 +75,40 @@ impl Hash for Object {
                 // 使用AntString特定的哈希逻辑
                 ant_string.value.hash(state);
             },
-            _ => {
+            Object::AntBoolean(ant_boolean) => {
+                ant_boolean.value.hash(state);
+            },
+            Object::AntTuple(ant_tuple) => {
+                // 元组按元素哈希, 以便作为字典键
+                ant_tuple.items.len().hash(state);
+                ant_tuple.items.iter().for_each(|item| item.hash(state));
+            },
+            Object::AntArray(ant_array) => {
+                ant_array.items.len().hash(state);
+                ant_array.items.iter().for_each(|item| item.hash(state));
+            },
+            Object::AntEnumValue(value) => {
+                // 枚举值按变体与字段值哈希, 与 equals 保持一致
+                value.variant.tag.hash(state);
+                value.values.iter().for_each(|item| item.hash(state));
+            },
+            Object::AntHashMap(ant_hash_map) => {
+                // 键值对无序, 仅哈希长度
+                ant_hash_map.map.len().hash(state);
+            },
+            Object::AntClass(ant_class) => {
+                ant_class.map.len().hash(state);
+            },
+            Object::AntNone(_)
+            | Object::AntUninit(_)
+            | Object::Closure(_)
+            | Object::CompiledFunction(_)
+            | Object::Method(_)
+            | Object::AntNativeFunction(_) => {
+                // 按值比较但难以按值哈希的类型, 仅哈希类型
+                std::mem::discriminant(self).hash(state);
+            },
+            Object::AntError(_) | Object::AntGenerator(_) => {
                 // 其他类型使用基于ID的哈希
                 let mut x = self.get_id() as u64;
                 x = x.wrapping_mul(0x9e3779b97f4a7c15);
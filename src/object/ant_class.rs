@@ -1,9 +1,11 @@
 use std::any::Any;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use crate::impl_object;
 use crate::obj_enum::object::Object;
+use crate::object::ant_enum_value::EnumVariant;
 use crate::object::id_counter::next_id;
 use crate::object::object::{IAntObject, ObjectType, CLASS};
 
@@ -12,6 +14,91 @@ pub struct AntClass {
     pub id: usize,
     pub name: Rc<str>,
     pub map: HashMap<String, Object>,
+    pub base: Option<Rc<RefCell<Object>>>, // 父类
+    pub readonly: Rc<HashSet<String>>,     // readonly 字段, 不能通过 OpSetField 修改
+    pub private: Rc<HashSet<String>>,      // private 成员, 只能在类自身的方法中通过 self 访问
+    pub interface: bool,                   // 接口, 不能被实例化
+    pub variants: Option<Rc<Vec<Rc<EnumVariant>>>>, // 枚举按声明顺序的变体, 普通类为 None
+}
+
+impl AntClass {
+    // 在自身及父类链上查找成员
+    pub fn get_member(&self, field: &str) -> Option<Object> {
+        if let Some(it) = self.map.get(field) {
+            return Some(it.clone());
+        }
+
+        match &self.base {
+            Some(base) => match &*base.borrow() {
+                Object::AntClass(base) => base.get_member(field),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+
+    // 在自身及父类链上查找声明了 private 成员 field 的类名
+    pub fn private_owner(&self, field: &str) -> Option<Rc<str>> {
+        if self.private.contains(field) {
+            return Some(self.name.clone());
+        }
+
+        match &self.base {
+            Some(base) => match &*base.borrow() {
+                Object::AntClass(base) => base.private_owner(field),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+
+    // 在自身及父类链上检查字段是否为 readonly
+    pub fn is_readonly(&self, field: &str) -> bool {
+        if self.readonly.contains(field) {
+            return true;
+        }
+
+        match &self.base {
+            Some(base) => match &*base.borrow() {
+                Object::AntClass(base) => base.is_readonly(field),
+                _ => false,
+            },
+            None => false,
+        }
+    }
+
+    // 自身及父类链上声明为 Uninit 的成员及声明它的类名, 按成员名排序
+    pub fn uninit_members(&self) -> Vec<(String, Rc<str>)> {
+        let mut members = match &self.base {
+            Some(base) => match &*base.borrow() {
+                Object::AntClass(base) => base.uninit_members(),
+                _ => vec![],
+            },
+            None => vec![],
+        };
+
+        for (k, v) in &self.map {
+            if matches!(v, Object::AntUninit(_)) && !members.iter().any(|(it, _)| it == k) {
+                members.push((k.clone(), self.name.clone()));
+            }
+        }
+
+        members.sort_by(|a, b| a.0.cmp(&b.0));
+
+        members
+    }
+
+    // 是否定义了 (不为 Uninit) 成员 field
+    pub fn defines(&self, field: &str) -> bool {
+        !matches!(self.get_member(field), None | Some(Object::AntUninit(_)))
+    }
+
+    // 查找第一个未被定义的成员
+    pub fn undefined_member(&self) -> Option<(String, Rc<str>)> {
+        self.uninit_members()
+            .into_iter()
+            .find(|(field, _)| !self.defines(field))
+    }
 }
 
 impl IAntObject for AntClass {
@@ -24,7 +111,7 @@ impl IAntObject for AntClass {
     }
 
     fn get_base(&self) -> Option<Object> {
-        None
+        self.base.as_ref().map(|base| base.borrow().clone())
     }
 
     fn get_id(&self) -> usize {
@@ -34,7 +121,8 @@ impl IAntObject for AntClass {
     fn inspect(&self) -> String {
         format!(
             "class {} {}",
-            self.name, if self.map.is_empty() { "{}" } else { "{ ... }" }
+            self.name,
+            if self.map.keys().all(|it| self.private.contains(it)) { "{}" } else { "{ ... }" }
         )
     }
 
@@ -60,6 +148,11 @@ impl From<(&str, HashMap<String, Object>)> for AntClass {
             id: next_id(),
             name: name.into(),
             map,
+            base: None,
+            readonly: Rc::default(),
+            private: Rc::default(),
+            interface: false,
+            variants: None,
         }
     }
 }
\ No newline at end of file
@@ -8,16 +8,24 @@ use crate::obj_enum::object::Object;
 use crate::object::ant_compiled_function::CompiledFunction;
 use crate::object::object::{CLOSURE, IAntObject, ObjectType};
 
+// 被捕获的变量 (upvalue)
+// Open 时指向仍在栈上的局部变量 (绝对栈索引), 所在函数返回后转为 Closed 持有该值
+#[derive(Debug, Clone)]
+pub enum Upvalue {
+    Open(usize),
+    Closed(Rc<RefCell<Object>>),
+}
+
 #[derive(Clone)]
 pub struct Closure {
     pub func: CompiledFunction,
-    pub free: Rc<RefCell<Vec<Object>>>,
+    pub free: Rc<Vec<Rc<RefCell<Upvalue>>>>,
 }
 
 impl Hash for Closure {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.func.hash(state);
-        self.free.borrow().hash(state);
+        self.free.len().hash(state);
     }
 }
 
@@ -49,7 +57,9 @@ impl IAntObject for Closure {
     fn equals(&self, other: &dyn IAntObject) -> bool {
         other.get_id() == self.get_id()
             || if let Some(it) = other.as_any().downcast_ref::<Closure>() {
-                it.func == self.func && it.free == self.free
+                it.func == self.func
+                    && it.free.len() == self.free.len()
+                    && it.free.iter().zip(self.free.iter()).all(|(a, b)| Rc::ptr_eq(a, b))
             } else {
                 false
             }
@@ -6,6 +6,32 @@ use crate::byte_code_vm::scope_info::ScopeInfo;
 use crate::impl_object;
 use crate::obj_enum::object::Object;
 use crate::object::object::{COMPILED_FUNCTION, IAntObject, ObjectType};
+use crate::object::type_hint::TypeHint;
+
+// 形参信息, 用于绑定默认参数、关键字参数与可变参数
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParamInfo {
+    pub names: Rc<[Rc<str>]>, // 普通形参名 (不含 *rest 与 **opts)
+    pub required: usize,      // 没有默认值的普通形参个数
+    pub rest: bool,           // 是否有 *rest
+    pub keywords: bool,       // 是否有 **opts
+    pub type_hints: Rc<[Option<TypeHint>]>, // 普通形参的类型注解
+}
+
+impl ParamInfo {
+    // 只有普通形参且都没有默认值, 可按位置直接绑定
+    pub fn is_positional(&self) -> bool {
+        self.required == self.names.len() && !self.rest && !self.keywords
+    }
+}
+
+// 创建闭包时捕获变量的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capture {
+    Local(usize),   // 外层函数的局部变量
+    Free(usize),    // 外层闭包已捕获的变量
+    CurrentClosure, // 外层函数自身
+}
 
 #[derive(Eq, Hash)]
 pub struct CompiledFunction {
@@ -14,6 +40,11 @@ pub struct CompiledFunction {
     pub instructions: Rc<[u8]>,
     pub local_count: usize,
     pub param_count: usize,
+    pub params: Rc<ParamInfo>,
+    pub return_type: Option<Rc<TypeHint>>,
+    pub unchecked: bool, // 为 true 时跳过类型注解检查 (#[unchecked])
+    pub is_generator: bool, // 函数体中含有 yield, 调用时返回生成器
+    pub captures: Rc<[Capture]>,
     pub scope_info: ScopeInfo,
 }
 
@@ -25,6 +56,11 @@ impl Clone for CompiledFunction {
             instructions: self.instructions.clone(),
             local_count: self.local_count,
             param_count: self.param_count,
+            params: self.params.clone(),
+            return_type: self.return_type.clone(),
+            unchecked: self.unchecked,
+            is_generator: self.is_generator,
+            captures: self.captures.clone(),
             scope_info: self.scope_info.clone(),
         }
     }
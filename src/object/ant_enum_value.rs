@@ -0,0 +1,107 @@
+use std::any::Any;
+use std::rc::Rc;
+
+use crate::impl_object;
+use crate::obj_enum::object::Object;
+use crate::object::id_counter::next_id;
+use crate::object::object::{ENUM, IAntObject, ObjectType, STRING};
+
+// 枚举变体的信息, 由同一变体构造的值共享
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+    pub enum_name: Rc<str>,
+    pub name: Rc<str>,
+    pub tag: usize, // 变体在枚举中的序号
+    pub fields: Rc<[Rc<str>]>,
+}
+
+// 枚举值, 如 Shape.Circle(2)
+#[derive(Clone)]
+pub struct AntEnumValue {
+    id: usize,
+    pub variant: Rc<EnumVariant>,
+    pub values: Vec<Object>,
+}
+
+impl AntEnumValue {
+    pub fn new(variant: Rc<EnumVariant>, values: Vec<Object>) -> Self {
+        Self {
+            id: next_id(),
+            variant,
+            values,
+        }
+    }
+
+    pub fn get_field(&self, field: &str) -> Option<&Object> {
+        self.variant
+            .fields
+            .iter()
+            .position(|it| &**it == field)
+            .map(|i| &self.values[i])
+    }
+
+    // 与另一个值是否为同一枚举的同一变体
+    pub fn same_variant(&self, other: &AntEnumValue) -> bool {
+        Rc::ptr_eq(&self.variant, &other.variant)
+            || (self.variant.tag == other.variant.tag
+                && self.variant.enum_name == other.variant.enum_name)
+    }
+}
+
+impl IAntObject for AntEnumValue {
+    fn get_type(&self) -> ObjectType {
+        ENUM.to_string()
+    }
+
+    fn get_value(&self) -> Box<dyn Any> {
+        Box::new(
+            self.values
+                .iter()
+                .map(|item| item.get_value())
+                .collect::<Vec<Box<dyn Any>>>(),
+        )
+    }
+
+    fn get_base(&self) -> Option<Object> {
+        None
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+
+    fn inspect(&self) -> String {
+        if self.values.is_empty() {
+            return self.variant.name.to_string();
+        }
+
+        format!(
+            "{}({})",
+            self.variant.name,
+            self.values
+                .iter()
+                .map(|item| if item.get_type() != STRING {
+                    item.inspect()
+                } else {
+                    format!("\"{}\"", item.inspect())
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    fn equals(&self, other: &dyn IAntObject) -> bool {
+        other.get_id() == self.id
+            || if let Some(other) = other.as_any().downcast_ref::<AntEnumValue>() {
+                self.same_variant(other) && self.values == other.values
+            } else {
+                false
+            }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl_object!(AntEnumValue);
@@ -2,6 +2,7 @@ use std::any::Any;
 
 use crate::impl_object;
 use crate::obj_enum::object::Object;
+use crate::object::id_counter::next_id;
 use crate::object::object::{ERROR, IAntObject, ObjectType};
 
 #[derive(Clone)]
@@ -9,6 +10,18 @@ pub struct AntError {
     pub id: usize,
     pub error_name: String,
     pub message: String,
+    pub traceback: String,
+}
+
+impl AntError {
+    pub fn new(error_name: String, message: String, traceback: String) -> Self {
+        Self {
+            id: next_id(),
+            error_name,
+            message,
+            traceback,
+        }
+    }
 }
 
 impl IAntObject for AntError {
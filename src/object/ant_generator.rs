@@ -0,0 +1,75 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::byte_code_vm::vm::frame::Frame;
+use crate::impl_object;
+use crate::obj_enum::object::Object;
+use crate::object::ant_closure::Upvalue;
+use crate::object::id_counter::next_id;
+use crate::object::object::{GENERATOR, IAntObject, ObjectType};
+
+// 生成器被挂起时保存的状态
+// frame 为 None 表示生成器已结束; 挂起时异常处理器的 sp 保存为相对于 base_pointer 的偏移
+pub struct GeneratorState {
+    pub frame: Option<Frame>,
+    pub stack: Vec<Rc<RefCell<Object>>>, // 局部变量及运算中的栈内容 (base_pointer..sp)
+    pub upvalues: Vec<(Rc<RefCell<Upvalue>>, usize)>, // 挂起时被关闭的 upvalue 及其相对于 base_pointer 的偏移
+    pub return_value: Option<Rc<RefCell<Object>>>, // 生成器函数 return 的值
+    pub running: bool,
+}
+
+#[derive(Clone)]
+pub struct AntGenerator {
+    id: usize,
+    pub name: Rc<str>,
+    pub state: Rc<RefCell<GeneratorState>>,
+}
+
+impl AntGenerator {
+    pub fn new(frame: Frame, stack: Vec<Rc<RefCell<Object>>>) -> Self {
+        Self {
+            id: next_id(),
+            name: frame.closure.func.scope_info.scope_name.clone(),
+            state: Rc::new(RefCell::new(GeneratorState {
+                frame: Some(frame),
+                stack,
+                upvalues: vec![],
+                return_value: None,
+                running: false,
+            })),
+        }
+    }
+}
+
+impl IAntObject for AntGenerator {
+    fn get_type(&self) -> ObjectType {
+        GENERATOR.to_string()
+    }
+
+    fn get_value(&self) -> Box<dyn Any> {
+        Box::new(self.id)
+    }
+
+    fn get_base(&self) -> Option<Object> {
+        None
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+
+    fn inspect(&self) -> String {
+        format!("<Generator {}>", self.name)
+    }
+
+    fn equals(&self, other: &dyn IAntObject) -> bool {
+        other.get_id() == self.id
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl_object!(AntGenerator);
@@ -0,0 +1,75 @@
+use std::any::Any;
+
+use crate::impl_object;
+use crate::obj_enum::object::Object;
+use crate::object::id_counter::next_id;
+use crate::object::object::{IAntObject, ObjectType, STRING, TUPLE};
+
+// 不可变元组, 创建后元素不可修改
+#[derive(Clone)]
+pub struct AntTuple {
+    id: usize,
+    pub items: Vec<Object>,
+}
+
+impl IAntObject for AntTuple {
+    fn get_type(&self) -> ObjectType {
+        TUPLE.to_string()
+    }
+
+    fn get_value(&self) -> Box<dyn Any> {
+        Box::new(
+            self.items
+                .iter()
+                .map(|item| item.get_value())
+                .collect::<Vec<Box<dyn Any>>>(),
+        )
+    }
+
+    fn get_base(&self) -> Option<Object> {
+        None
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+
+    fn inspect(&self) -> String {
+        format!(
+            "({})",
+            self.items
+                .iter()
+                .map(|item| if item.get_type() != STRING {
+                    item.inspect()
+                } else {
+                    format!("\"{}\"", item.inspect())
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    fn equals(&self, other: &dyn IAntObject) -> bool {
+        other.get_id() == self.id
+            || if let Some(tuple) = other.as_any().downcast_ref::<AntTuple>() {
+                tuple.items == self.items
+            } else {
+                false
+            }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl_object!(AntTuple);
+
+impl From<Vec<Object>> for AntTuple {
+    fn from(items: Vec<Object>) -> Self {
+        AntTuple {
+            id: next_id(),
+            items,
+        }
+    }
+}
@@ -1,10 +1,13 @@
 pub mod id_counter;
 pub mod ant_array;
+pub mod ant_tuple;
 pub mod ant_boolean;
 pub mod ant_class;
 pub mod ant_closure;
+pub mod ant_generator;
 pub mod ant_compiled_function;
 pub mod ant_double;
+pub mod ant_enum_value;
 pub mod ant_error;
 pub mod ant_int;
 pub mod ant_native_function;
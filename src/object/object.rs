@@ -10,12 +10,15 @@ use crate::impl_object;
 use crate::obj_enum::object::Object;
 
 use crate::object::ant_array::AntArray;
+use crate::object::ant_tuple::AntTuple;
 use crate::object::ant_hash_map::AntHashMap;
 use crate::object::ant_boolean::AntBoolean;
 use crate::object::ant_class::AntClass;
 use crate::object::ant_closure::Closure;
+use crate::object::ant_generator::AntGenerator;
 use crate::object::ant_compiled_function::CompiledFunction;
 use crate::object::ant_double::AntDouble;
+use crate::object::ant_enum_value::AntEnumValue;
 use crate::object::ant_error::AntError;
 use crate::object::ant_int::AntInt;
 use crate::object::ant_method::Method;
@@ -40,10 +43,13 @@ pub const ERROR: &str = "Error";
 pub const NATIVE_FUNCTION: &str = "NativeFunction";
 pub const COMPILED_FUNCTION: &str = "CompiledFunction";
 pub const CLOSURE: &str = "Closure";
+pub const GENERATOR: &str = "Generator";
 pub const METHOD: &str = "Method";
 pub const ARRAY: &str = "Array";
+pub const TUPLE: &str = "Tuple";
 pub const HASH_MAP: &str = "HashMap";
 pub const CLASS: &str = "Class";
+pub const ENUM: &str = "Enum";
 
 pub trait AsAnyMut {
     fn as_any_mut(&mut self) -> &mut dyn Any;
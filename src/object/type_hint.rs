@@ -2,11 +2,17 @@ use std::rc::Rc;
 
 use super::object::ObjectType;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeHint {
     pub types: Vec<ObjectType>,
 }
 
+impl std::fmt::Display for TypeHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.types.join(" | "))
+    }
+}
+
 impl TypeHint {
     pub fn new(types: Vec<ObjectType>) -> Self {
         Self { types }
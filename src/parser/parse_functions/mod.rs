@@ -9,14 +9,17 @@ pub mod parse_else_if_expression;
 pub mod parse_function_expression;
 pub mod parse_ident;
 pub mod parse_if_expression;
+pub mod parse_match_expression;
 pub mod parse_index_expression;
 pub mod parse_infix_expression;
 pub mod parse_let_statement;
+pub mod parse_destructuring_let_statement;
 pub mod parse_number;
 pub mod parse_object_member_expression;
 pub mod parse_prefix_expression;
 pub mod parse_return_statement;
 pub mod parse_string;
+pub mod parse_format_string;
 pub mod parse_test_print_statement;
 pub mod parse_tuple_expression;
 pub mod parse_while_statement;
@@ -25,4 +28,14 @@ pub mod parse_hash_literal;
 pub mod parse_use_statement;
 pub mod parse_decorator;
 pub mod parse_break;
-pub mod parse_continue;
\ No newline at end of file
+pub mod parse_continue;
+pub mod parse_for_statement;
+pub mod parse_try_statement;
+pub mod parse_import_statement;
+pub mod parse_from_import_statement;
+pub mod parse_function_params;
+pub mod parse_type_hint;
+pub mod parse_readonly_let_statement;
+pub mod parse_visibility_statement;
+pub mod parse_yield_statement;
+pub mod parse_enum_statement;
\ No newline at end of file
@@ -1,16 +1,67 @@
 use crate::ast::expr::Expression;
+use crate::parser::precedence::Precedence;
 use crate::token::token_type::TokenType;
 
 use crate::ast::expressions::call_expression::create_call_expression;
 use crate::parser::parser::Parser;
 
+type CallArguments = (Vec<Box<Expression>>, Vec<(String, Box<Expression>)>);
+
+// 解析实参列表 (位置实参在前, name: value 形式的关键字实参在后), 结束时当前词法单元为右括号
+fn parse_call_arguments(parser: &mut Parser) -> Option<CallArguments> {
+    let mut args = vec![];
+    let mut keyword_args: Vec<(String, Box<Expression>)> = vec![];
+
+    while !parser.peek_token_is(TokenType::RParen) {
+        parser.next_token();
+
+        if parser.cur_token_is(TokenType::Ident) && parser.peek_token_is(TokenType::Colon) {
+            let name = parser.cur_token.value.clone();
+
+            if keyword_args.iter().any(|(it, _)| it == &name) {
+                parser.push_err(format!("duplicate keyword argument '{name}'"));
+                return None;
+            }
+
+            parser.next_token(); // 前进到冒号
+            parser.next_token(); // 离开冒号
+
+            keyword_args.push((name, Box::new(parser.parse_expression(Precedence::Lowest)?)));
+        } else {
+            if !keyword_args.is_empty() {
+                parser.push_err(String::from("positional argument follows keyword argument"));
+                return None;
+            }
+
+            args.push(Box::new(parser.parse_expression(Precedence::Lowest)?));
+        }
+
+        if !parser.peek_token_is(TokenType::RParen) && !parser.expect_peek(TokenType::Comma) {
+            return None;
+        }
+
+        if parser.peek_token_is(TokenType::Comma) {
+            parser.next_token(); // 前进到逗号, 允许尾逗号
+        }
+    }
+
+    parser.next_token(); // 前进到右括号
+
+    Some((args, keyword_args))
+}
+
 pub fn parse_call_expression(
     parser: &mut Parser,
     left: Expression,
 ) -> Option<Expression> {
     let token = parser.cur_token.clone();
 
-    let args = parser.parse_expression_list(TokenType::RParen);
+    let (args, keyword_args) = parse_call_arguments(parser)?;
 
-    Some(Expression::CallExpression(create_call_expression(token, Box::new(left), args)))
+    Some(Expression::CallExpression(create_call_expression(
+        token,
+        Box::new(left),
+        args,
+        keyword_args,
+    )))
 }
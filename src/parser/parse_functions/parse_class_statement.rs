@@ -6,8 +6,10 @@ use crate::token::token_type::TokenType;
 
 use super::parse_block_statement::parse_block_statement;
 
+// class A : B { ... } 或 interface A : B { ... }
 pub fn parse_class_statement(parser: &mut Parser) -> Option<Statement> {
     let token = parser.cur_token.clone();
+    let interface = token.token_type == TokenType::Interface;
 
     let mut parent_class_ident = None;
 
@@ -63,6 +65,7 @@ pub fn parse_class_statement(parser: &mut Parser) -> Option<Statement> {
                     class_ident,
                     parent_class_ident,
                     block,
+                    interface,
                 )))
             } else {
                 None
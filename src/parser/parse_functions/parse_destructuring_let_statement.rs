@@ -0,0 +1,125 @@
+use crate::ast::statements::destructuring_let_statement::{
+    create_destructuring_let_statement, DestructureTarget,
+};
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token::Token;
+use crate::token::token_type::TokenType;
+
+// 当前词法单元是否为解构目标的开始
+pub fn is_destructure_start(parser: &Parser) -> bool {
+    parser.cur_token_is(TokenType::LBracket)
+        || parser.cur_token_is(TokenType::LBrace)
+        || (parser.cur_token_is(TokenType::Ident) && parser.peek_token_is(TokenType::LBrace))
+}
+
+// 解析 {name, age: a} 中的一项
+fn parse_field(parser: &mut Parser) -> Option<(String, DestructureTarget)> {
+    let key = match parser.cur_token.token_type {
+        TokenType::Ident | TokenType::String => parser.cur_token.value.clone(),
+        _ => {
+            parser.push_err(format!(
+                "expected a field name to destructure, got: {}",
+                parser.cur_token.value
+            ));
+            return None;
+        }
+    };
+
+    if !parser.peek_token_is(TokenType::Colon) {
+        if parser.cur_token_is(TokenType::String) {
+            parser.push_err(format!("missing binding name for key \"{key}\""));
+            return None;
+        }
+
+        return Some((key.clone(), DestructureTarget::Name(key)));
+    }
+
+    parser.next_token(); // 前进到冒号
+    parser.next_token(); // 离开冒号
+
+    Some((key, parse_destructure_target(parser)?))
+}
+
+pub fn parse_destructure_target(parser: &mut Parser) -> Option<DestructureTarget> {
+    match parser.cur_token.token_type {
+        TokenType::Ident if parser.peek_token_is(TokenType::LBrace) => {
+            let name = parser.cur_token.value.clone();
+
+            parser.next_token(); // 前进到左大括号
+
+            Some(DestructureTarget::Object(
+                name,
+                parser.parse_pattern_items(TokenType::RBrace, parse_field)?,
+            ))
+        }
+
+        TokenType::Ident => Some(DestructureTarget::Name(parser.cur_token.value.clone())),
+
+        TokenType::LBracket => {
+            let mut rest = None;
+
+            let items = parser.parse_pattern_items(TokenType::RBracket, |parser| {
+                if rest.is_some() {
+                    parser.push_err(String::from("rest element must be the last element"));
+                    return None;
+                }
+
+                if !parser.cur_token_is(TokenType::Ellipsis) {
+                    return Some(Some(parse_destructure_target(parser)?));
+                }
+
+                if !parser.expect_peek(TokenType::Ident) {
+                    return None;
+                }
+
+                parser.next_token(); // 前进到标识符
+
+                rest = Some(parser.cur_token.value.clone());
+
+                Some(None)
+            })?;
+
+            Some(DestructureTarget::Array(items.into_iter().flatten().collect(), rest))
+        }
+
+        TokenType::LBrace => Some(DestructureTarget::Hash(
+            parser.parse_pattern_items(TokenType::RBrace, parse_field)?,
+        )),
+
+        _ => {
+            parser.push_err(format!("invalid destructuring target: {}", parser.cur_token.value));
+            None
+        }
+    }
+}
+
+pub fn parse_destructuring_let_statement(parser: &mut Parser, token: Token) -> Option<Statement> {
+    let target = parse_destructure_target(parser)?;
+
+    if !parser.expect_peek(TokenType::Assign) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到等号
+    parser.next_token(); // 离开等号
+
+    let value = match parser.parse_expression(Precedence::Lowest) {
+        Some(it) => it,
+        None => {
+            parser.push_err(String::from("missing expression."));
+            return None;
+        }
+    };
+
+    if parser.peek_token_is(TokenType::Semicolon) {
+        parser.next_token();
+    }
+
+    Some(Statement::DestructuringLetStatement(create_destructuring_let_statement(
+        token,
+        target,
+        Box::new(value),
+    )))
+}
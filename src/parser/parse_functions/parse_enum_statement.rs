@@ -0,0 +1,102 @@
+use crate::ast::expressions::identifier::create_identifier;
+use crate::ast::statements::enum_statement::{create_enum_statement, EnumVariantDecl};
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType;
+
+// 解析变体的字段列表, 开始时当前词法单元为左括号, 结束时为右括号
+fn parse_variant_fields(parser: &mut Parser, variant: &str) -> Option<Vec<String>> {
+    let mut fields: Vec<String> = vec![];
+
+    while !parser.peek_token_is(TokenType::RParen) {
+        if !parser.expect_peek(TokenType::Ident) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到字段名
+
+        let field = parser.cur_token.value.clone();
+
+        if fields.contains(&field) {
+            parser.push_err(format!("duplicate field '{field}' of variant '{variant}'"));
+            return None;
+        }
+
+        fields.push(field);
+
+        if !parser.peek_token_is(TokenType::RParen) && !parser.expect_peek(TokenType::Comma) {
+            return None;
+        }
+
+        if parser.peek_token_is(TokenType::Comma) {
+            parser.next_token(); // 前进到逗号, 允许尾逗号
+        }
+    }
+
+    parser.next_token(); // 前进到右括号
+
+    Some(fields)
+}
+
+// enum Shape { Circle(r), Rect(w, h), Empty }
+pub fn parse_enum_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    if !parser.expect_peek(TokenType::Ident) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到枚举名
+
+    let name = create_identifier(parser.cur_token.clone(), parser.cur_token.value.clone());
+
+    if !parser.expect_peek(TokenType::LBrace) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到左大括号
+
+    let mut variants: Vec<EnumVariantDecl> = vec![];
+
+    while !parser.peek_token_is(TokenType::RBrace) {
+        if !parser.expect_peek(TokenType::Ident) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到变体名
+
+        let variant = parser.cur_token.value.clone();
+
+        if variants.iter().any(|it| it.name == variant) {
+            parser.push_err(format!("duplicate variant '{variant}' of enum '{}'", name.value));
+            return None;
+        }
+
+        let fields = if parser.peek_token_is(TokenType::LParen) {
+            parser.next_token(); // 前进到左括号
+
+            parse_variant_fields(parser, &variant)?
+        } else {
+            vec![]
+        };
+
+        variants.push(EnumVariantDecl { name: variant, fields });
+
+        if !parser.peek_token_is(TokenType::RBrace) && !parser.expect_peek(TokenType::Comma) {
+            return None;
+        }
+
+        if parser.peek_token_is(TokenType::Comma) {
+            parser.next_token(); // 前进到逗号, 允许尾逗号
+        }
+    }
+
+    parser.next_token(); // 前进到右大括号
+
+    if variants.is_empty() {
+        parser.push_err(format!("enum '{}' has no variants", name.value));
+        return None;
+    }
+
+    Some(Statement::EnumStatement(create_enum_statement(token, name, variants)))
+}
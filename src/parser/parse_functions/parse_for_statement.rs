@@ -0,0 +1,59 @@
+use crate::ast::ast::INode;
+use crate::ast::expressions::identifier::create_identifier;
+use crate::ast::stmt::Statement;
+use crate::ast::statements::for_statement::create_for_statement;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token_type::TokenType::{Ident, In, LBrace};
+
+use super::parse_block_statement::parse_block_statement;
+
+pub fn parse_for_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    parser.next_token(); // 离开 for 词法单元
+
+    // 循环变量
+    if !parser.expect_cur(Ident) {
+        return None;
+    }
+
+    let var = create_identifier(parser.cur_token.clone(), parser.cur_token.value.clone());
+
+    if !parser.expect_peek(In) {
+        return None;
+    }
+
+    parser.next_token(); // 离开循环变量
+    parser.next_token(); // 离开 in 词法单元
+
+    // 被迭代的对象
+    let iterable = parser.parse_expression(Precedence::Lowest);
+    if iterable.is_none() {
+        parser.push_err(String::from("missing iterable expression."));
+
+        return None;
+    }
+
+    if !parser.expect_peek(LBrace) {
+        return None;
+    }
+
+    parser.next_token(); // 离开被迭代的表达式
+
+    let block = parse_block_statement(parser);
+    if block.is_none() {
+        parser.push_err(String::from("missing for body."));
+        return None;
+    }
+
+    Some(Statement::ForStatement(create_for_statement(
+        token,
+        var,
+        Box::new(iterable.unwrap()),
+        match block.as_ref().unwrap() {
+            Statement::BlockStatement(it) => it.clone(),
+            _ => panic!("expected an block, got: {}", block.unwrap().to_string())
+        }
+    )))
+}
@@ -0,0 +1,56 @@
+use crate::ast::expr::Expression;
+use crate::ast::expressions::format_string_literal::{create_format_string_literal, FormatStringPart};
+use crate::lexer::format_string::{scan_format_string, FormatStringPiece};
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token_type::TokenType;
+
+pub fn parse_format_string(parser: &mut Parser) -> Option<Expression> {
+    let token = parser.cur_token.clone();
+
+    // 词法分析阶段只保存了字符串体, 重新扫描一次 (补上结束双引号)
+    let chars = format!("{}\"", token.value).chars().collect::<Vec<char>>();
+
+    let pieces = match scan_format_string(&chars) {
+        Ok((pieces, _)) => pieces,
+        Err(msg) => {
+            parser.push_err(msg);
+            return None;
+        }
+    };
+
+    let mut parts = vec![];
+
+    for piece in pieces {
+        match piece {
+            FormatStringPiece::Literal(s) => parts.push(FormatStringPart::Literal(s)),
+
+            FormatStringPiece::Expression { source, spec } => {
+                let mut lexer = Lexer::new(source.clone(), token.file.clone());
+                let tokens = lexer.get_tokens();
+
+                if lexer.contains_error() {
+                    parser.push_err(format!("invalid expression in format string: '{source}'"));
+                    return None;
+                }
+
+                let mut sub_parser = Parser::new(tokens);
+                let expr = sub_parser.parse_expression(Precedence::Lowest);
+
+                // 表达式必须完整地占满 {} 中的内容
+                let expr = match expr {
+                    Some(expr) if !sub_parser.contains_error() && sub_parser.peek_token_is(TokenType::Eof) => expr,
+                    _ => {
+                        parser.push_err(format!("invalid expression in format string: '{source}'"));
+                        return None;
+                    }
+                };
+
+                parts.push(FormatStringPart::Expression(Box::new(expr), spec))
+            }
+        }
+    }
+
+    Some(Expression::FormatStringLiteral(create_format_string_literal(token, parts)))
+}
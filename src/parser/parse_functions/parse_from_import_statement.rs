@@ -0,0 +1,64 @@
+use crate::ast::expressions::identifier::create_identifier;
+use crate::ast::statements::from_import_statement::create_from_import_statement;
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType::{As, Comma, Ident, Import, Semicolon};
+
+use super::parse_import_statement::parse_module_path;
+
+pub fn parse_from_import_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    let path = parse_module_path(parser)?;
+
+    if !parser.expect_peek(Import) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到 import
+
+    let mut members = vec![];
+
+    loop {
+        if !parser.expect_peek(Ident) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到成员名
+
+        let name = create_identifier(parser.cur_token.clone(), parser.cur_token.value.clone());
+
+        let mut alias = None;
+
+        if parser.peek_token_is(As) {
+            parser.next_token(); // 前进到 as
+
+            if !parser.expect_peek(Ident) {
+                return None;
+            }
+
+            parser.next_token(); // 前进到别名
+
+            alias = Some(create_identifier(
+                parser.cur_token.clone(),
+                parser.cur_token.value.clone(),
+            ));
+        }
+
+        members.push((name, alias));
+
+        if !parser.peek_token_is(Comma) {
+            break;
+        }
+
+        parser.next_token(); // 前进到逗号
+    }
+
+    if parser.peek_token_is(Semicolon) {
+        parser.next_token();
+    }
+
+    Some(Statement::FromImportStatement(create_from_import_statement(
+        token, path, members,
+    )))
+}
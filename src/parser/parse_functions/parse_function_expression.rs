@@ -1,9 +1,10 @@
 use std::any::Any;
 
 use crate::ast::ast::INode;
-use crate::ast::expressions::identifier::Identifier;
 use crate::ast::stmt::Statement;
 use crate::parser::parse_functions::parse_block_statement::parse_block_statement;
+use crate::parser::parse_functions::parse_function_params::parse_function_params;
+use crate::parser::parse_functions::parse_type_hint::parse_type_hint;
 use crate::ast::expr::Expression;
 use crate::token::token_type::TokenType;
 
@@ -27,7 +28,7 @@ pub fn parse_function_expression(parser: &mut Parser) -> Option<Expression> {
 
     parser.next_token(); // 前进到左括号
 
-    let params = parser.parse_expression_list(TokenType::RParen);
+    let params = parse_function_params(parser)?;
 
     parser.next_token(); // 离开右括号 (正常应前进到左大括号 或者 '->' )
 
@@ -40,16 +41,7 @@ pub fn parse_function_expression(parser: &mut Parser) -> Option<Expression> {
 
         parser.next_token(); // 前进到 >
 
-        if !parser.expect_peek(TokenType::Ident) {
-            return None;
-        }
-
-        parser.next_token(); // 前进到 Ident
-
-        ret_type = Some(Identifier {
-            token: parser.cur_token.clone(),
-            value: parser.cur_token.value.clone()
-        });
+        ret_type = Some(parse_type_hint(parser)?);
 
         parser.next_token(); // 理应前进到左大括号
     }
@@ -67,6 +59,8 @@ pub fn parse_function_expression(parser: &mut Parser) -> Option<Expression> {
                 ))
             };
 
+            parser.mark_block_expression_end();
+
             Some(Expression::FunctionExpression(create_function_expression(
                 token,
                 name,
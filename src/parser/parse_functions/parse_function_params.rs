@@ -0,0 +1,104 @@
+use crate::ast::expressions::function_expression::{ParamKind, Parameter};
+use crate::ast::expressions::identifier::create_identifier;
+use crate::parser::parse_functions::parse_type_hint::parse_type_hint;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token_type::TokenType;
+
+// 检查形参顺序: 普通形参 (有默认值的在后) -> *rest -> **opts
+fn check_param_order(parser: &mut Parser, params: &[Parameter], param: &Parameter) -> bool {
+    if params.iter().any(|it| it.name.value == param.name.value) {
+        parser.push_err(format!("duplicate parameter '{}'", param.name.value));
+        return false;
+    }
+
+    let last_kind = params.last().map(|it| it.kind);
+
+    let message = match param.kind {
+        _ if last_kind == Some(ParamKind::Keywords) => "parameter cannot follow **keywords parameter",
+        ParamKind::Rest if last_kind == Some(ParamKind::Rest) => "only one *rest parameter is allowed",
+        ParamKind::Normal if last_kind == Some(ParamKind::Rest) => "parameter cannot follow *rest parameter",
+        ParamKind::Normal
+            if param.default.is_none() && params.iter().any(|it| it.default.is_some()) =>
+        {
+            "non-default parameter follows default parameter"
+        }
+        _ => return true,
+    };
+
+    parser.push_err(format!("{message}: '{}'", param.name.value));
+    false
+}
+
+// 解析形参列表, 开始时当前词法单元为左括号, 结束时为右括号
+pub fn parse_function_params(parser: &mut Parser) -> Option<Vec<Parameter>> {
+    let mut params: Vec<Parameter> = vec![];
+
+    while !parser.peek_token_is(TokenType::RParen) {
+        parser.next_token();
+
+        let kind = match parser.cur_token.token_type {
+            TokenType::Asterisk => ParamKind::Rest,
+            TokenType::Power => ParamKind::Keywords,
+            _ => ParamKind::Normal,
+        };
+
+        if kind != ParamKind::Normal {
+            parser.next_token(); // 离开 * 或 **
+        }
+
+        if !parser.cur_token_is(TokenType::Ident) {
+            parser.push_err(format!(
+                "expected a parameter name, got: {}",
+                parser.cur_token.value
+            ));
+            return None;
+        }
+
+        let name = create_identifier(parser.cur_token.clone(), parser.cur_token.value.clone());
+
+        // 仅普通形参支持类型注解
+        let type_hint = if kind == ParamKind::Normal && parser.peek_token_is(TokenType::Colon) {
+            parser.next_token(); // 前进到冒号
+
+            Some(parse_type_hint(parser)?)
+        } else {
+            None
+        };
+
+        let default = if kind == ParamKind::Normal && parser.peek_token_is(TokenType::Assign) {
+            parser.next_token(); // 前进到等号
+            parser.next_token(); // 离开等号
+
+            match parser.parse_expression(Precedence::Lowest) {
+                Some(it) => Some(Box::new(it)),
+                None => {
+                    parser.push_err(format!("missing default value of parameter '{}'", name.value));
+                    return None;
+                }
+            }
+        } else {
+            None
+        };
+
+        let param = Parameter { name, kind, type_hint, default };
+
+        if !check_param_order(parser, &params, &param) {
+            return None;
+        }
+
+        params.push(param);
+
+        if !parser.peek_token_is(TokenType::RParen) && !parser.expect_peek(TokenType::Comma) {
+            return None;
+        }
+
+        if parser.peek_token_is(TokenType::Comma) {
+            parser.next_token(); // 前进到逗号, 允许尾逗号
+        }
+    }
+
+    parser.next_token(); // 前进到右括号
+
+    Some(params)
+}
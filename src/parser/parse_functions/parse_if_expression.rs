@@ -71,6 +71,8 @@ pub fn parse_if_expression(parser: &mut Parser) -> Option<Expression> {
             return None;
         }
 
+        parser.mark_block_expression_end();
+
         return Some(Expression::IfExpression(create_if_expression(
             token,
             Box::new(condition),
@@ -80,6 +82,8 @@ pub fn parse_if_expression(parser: &mut Parser) -> Option<Expression> {
         )));
     }
 
+    parser.mark_block_expression_end();
+
     Some(Expression::IfExpression(create_if_expression(
         token,
         Box::new(condition),
@@ -0,0 +1,67 @@
+use crate::ast::expressions::identifier::{Identifier, create_identifier};
+use crate::ast::statements::import_statement::create_import_statement;
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType::{As, Dot, Ident, Semicolon};
+
+// 解析 a.b.c 形式的模块路径, 结束时停在路径的最后一个标识符上
+pub fn parse_module_path(parser: &mut Parser) -> Option<Vec<Identifier>> {
+    if !parser.expect_peek(Ident) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到标识符
+
+    let mut path = vec![create_identifier(
+        parser.cur_token.clone(),
+        parser.cur_token.value.clone(),
+    )];
+
+    while parser.peek_token_is(Dot) {
+        parser.next_token(); // 前进到点
+
+        if !parser.expect_peek(Ident) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到标识符
+
+        path.push(create_identifier(
+            parser.cur_token.clone(),
+            parser.cur_token.value.clone(),
+        ));
+    }
+
+    Some(path)
+}
+
+pub fn parse_import_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    let path = parse_module_path(parser)?;
+
+    let mut alias = None;
+
+    if parser.peek_token_is(As) {
+        parser.next_token(); // 前进到 as
+
+        if !parser.expect_peek(Ident) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到别名
+
+        alias = Some(create_identifier(
+            parser.cur_token.clone(),
+            parser.cur_token.value.clone(),
+        ));
+    }
+
+    if parser.peek_token_is(Semicolon) {
+        parser.next_token();
+    }
+
+    Some(Statement::ImportStatement(create_import_statement(
+        token, path, alias,
+    )))
+}
@@ -14,7 +14,8 @@ pub fn parse_infix_expression(
     let precedence = get_token_precedence(parser.cur_token.token_type);
     parser.next_token(); // 离开运算符
 
-    let right_expression = parser.parse_expression(if operator.value == "+" {
+    // ** 为右结合
+    let right_expression = parser.parse_expression(if operator.value == "+" || operator.value == "**" {
         precedence - 1
     } else {
         precedence
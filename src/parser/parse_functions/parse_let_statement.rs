@@ -1,7 +1,12 @@
+use crate::ast::expr::Expression;
 use crate::ast::expressions::identifier::create_identifier;
+use crate::ast::expressions::uninit_literal::create_uninit_literal;
 use crate::ast::statements::let_statement::create_let_statement;
 use crate::ast::statements::let_statement::create_let_statement_with_type;
 use crate::ast::stmt::Statement;
+use crate::parser::parse_functions::parse_destructuring_let_statement::{
+    is_destructure_start, parse_destructuring_let_statement,
+};
 use crate::parser::parser::Parser;
 use crate::parser::precedence::Precedence;
 use crate::token::token_type::TokenType;
@@ -15,6 +20,11 @@ pub fn parse_let_statement(parser: &mut Parser) -> Option<Statement> {
 
     parser.next_token();
 
+    // 解构赋值: let [a, b] = ... / let {a, b} = ... / let A {a, b} = ...
+    if is_destructure_start(parser) {
+        return parse_destructuring_let_statement(parser, token);
+    }
+
     // 检查当前词法单元是否为标识符
     if !parser.expect_cur(Ident) {
         return None;
@@ -23,11 +33,28 @@ pub fn parse_let_statement(parser: &mut Parser) -> Option<Statement> {
     // 设置标识符
     ident = create_identifier(parser.cur_token.clone(), parser.cur_token.value.clone());
 
-    // 前进，脱离标识符
-    parser.next_token();
+    // 没有值的声明 (let area; / 以换行结尾), 值为 Uninit
+    if parser.peek_token_is(Semicolon)
+        || parser.peek_token_is(TokenType::RBrace)
+        || parser.peek_token_is(TokenType::Eof)
+        || parser.peek_after_newline
+    {
+        let uninit = create_uninit_literal(ident.token.clone());
+
+        if parser.peek_token_is(Semicolon) {
+            parser.next_token();
+        }
+
+        return Some(Statement::LetStatement(create_let_statement(
+            token,
+            ident,
+            Box::new(Expression::UninitLiteral(uninit)),
+        )));
+    }
 
-    if parser.cur_token_is(TokenType::Colon) {
-        // 前进，脱离冒号
+    if parser.peek_token_is(TokenType::Colon) {
+        // 前进，脱离标识符与冒号
+        parser.next_token();
         parser.next_token();
 
         let type_hint_expr = parser.parse_expression(Precedence::Assignment);
@@ -38,12 +65,16 @@ pub fn parse_let_statement(parser: &mut Parser) -> Option<Statement> {
             parser.push_err(format!("missing type hint expression."));
             return None;
         }
+    }
 
-        // 前进，脱离表达式
-        parser.next_token();
+    // 标识符 (或类型注解) 之后必须是等号
+    if !parser.peek_token_is(TokenType::Assign) {
+        parser.push_err(format!("expected '=' after '{}', got: {}", ident.value, parser.peek_token.value));
+        return None;
     }
 
-    // 前进，脱离等号
+    // 前进到等号并脱离等号
+    parser.next_token();
     parser.next_token();
 
     // 解析表达式
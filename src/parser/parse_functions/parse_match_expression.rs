@@ -0,0 +1,212 @@
+use crate::ast::ast::ExpressionStatement;
+use crate::ast::expr::Expression;
+use crate::ast::expressions::match_expression::{create_match_expression, MatchArm, Pattern};
+use crate::ast::stmt::Statement;
+use crate::parser::parse_functions::parse_block_statement::parse_block_statement;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token_type::TokenType;
+
+fn is_literal_token(token_type: TokenType) -> bool {
+    matches!(
+        token_type,
+        TokenType::IntegerBig
+            | TokenType::Integer64
+            | TokenType::String
+            | TokenType::BoolTrue
+            | TokenType::BoolFalse
+            | TokenType::None
+    )
+}
+
+// 解析字面量 (负数以前缀表达式的形式保存)
+fn parse_literal(parser: &mut Parser) -> Option<Expression> {
+    if parser.cur_token_is(TokenType::Minus)
+        && !matches!(parser.peek_token.token_type, TokenType::IntegerBig | TokenType::Integer64)
+    {
+        parser.push_err(String::from("expected a number after '-' in pattern"));
+        return None;
+    }
+
+    // 仅允许 '.' (浮点数字面量), 避免解析其他中缀表达式
+    parser.parse_expression(Precedence::Index)
+}
+
+fn parse_hash_pattern_pair(parser: &mut Parser) -> Option<(Box<Expression>, Pattern)> {
+    if !is_literal_token(parser.cur_token.token_type) && !parser.cur_token_is(TokenType::Minus) {
+        parser.push_err(format!(
+            "expected a literal key in hash pattern, got: {}",
+            parser.cur_token.value
+        ));
+        return None;
+    }
+
+    let key = parse_literal(parser)?;
+
+    if !parser.expect_peek(TokenType::Colon) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到冒号
+    parser.next_token(); // 离开冒号
+
+    Some((Box::new(key), parse_pattern(parser)?))
+}
+
+fn parse_class_pattern_field(parser: &mut Parser) -> Option<(String, Pattern)> {
+    if !parser.cur_token_is(TokenType::Ident) {
+        parser.push_err(format!(
+            "expected a field name in class pattern, got: {}",
+            parser.cur_token.value
+        ));
+        return None;
+    }
+
+    let field = parser.cur_token.value.clone();
+
+    // 简写: A { x } 等价于 A { x: x }
+    if !parser.peek_token_is(TokenType::Colon) {
+        return Some((field.clone(), Pattern::Binding(field)));
+    }
+
+    parser.next_token(); // 前进到冒号
+    parser.next_token(); // 离开冒号
+
+    Some((field, parse_pattern(parser)?))
+}
+
+pub fn parse_pattern(parser: &mut Parser) -> Option<Pattern> {
+    match parser.cur_token.token_type {
+        TokenType::Ident if parser.cur_token.value == "_" => Some(Pattern::Wildcard),
+
+        // 限定的变体模式: E.V 或 E::V, 可带字段 E.V { field: p }
+        TokenType::Ident
+            if parser.peek_token_is(TokenType::Dot) || parser.peek_token_is(TokenType::GetClassMember) =>
+        {
+            let enum_name = parser.cur_token.value.clone();
+
+            parser.next_token(); // 前进到 . 或 ::
+
+            if !parser.expect_peek(TokenType::Ident) {
+                return None;
+            }
+
+            parser.next_token(); // 前进到变体名
+
+            let name = parser.cur_token.value.clone();
+
+            let fields = if parser.peek_token_is(TokenType::LBrace) {
+                parser.next_token(); // 前进到左大括号
+
+                parser.parse_pattern_items(TokenType::RBrace, parse_class_pattern_field)?
+            } else {
+                vec![]
+            };
+
+            Some(Pattern::Variant(enum_name, name, fields))
+        }
+
+        TokenType::Ident if parser.peek_token_is(TokenType::LBrace) => {
+            let name = parser.cur_token.value.clone();
+
+            parser.next_token(); // 前进到左大括号
+
+            let fields = parser.parse_pattern_items(TokenType::RBrace, parse_class_pattern_field)?;
+
+            Some(Pattern::Class(name, fields))
+        }
+
+        TokenType::Ident => Some(Pattern::Binding(parser.cur_token.value.clone())),
+
+        TokenType::LBracket => Some(Pattern::Array(
+            parser.parse_pattern_items(TokenType::RBracket, parse_pattern)?,
+        )),
+
+        TokenType::LBrace => Some(Pattern::Hash(
+            parser.parse_pattern_items(TokenType::RBrace, parse_hash_pattern_pair)?,
+        )),
+
+        it if is_literal_token(it) || it == TokenType::Minus => {
+            Some(Pattern::Literal(Box::new(parse_literal(parser)?)))
+        }
+
+        _ => {
+            parser.push_err(format!("invalid pattern: {}", parser.cur_token.value));
+            None
+        }
+    }
+}
+
+pub fn parse_match_expression(parser: &mut Parser) -> Option<Expression> {
+    let token = parser.cur_token.clone();
+
+    parser.next_token(); // 离开 match 词法单元
+
+    let value = match parser.parse_expression(Precedence::Lowest) {
+        Some(expr) => expr,
+        None => {
+            parser.push_err(String::from("missing match value"));
+            return None;
+        }
+    };
+
+    if !parser.expect_peek(TokenType::LBrace) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到左大括号
+
+    let mut arms = vec![];
+
+    while !parser.peek_token_is(TokenType::RBrace) {
+        if parser.peek_token_is(TokenType::Eof) {
+            parser.push_err(String::from("missing '}' of match expression"));
+            return None;
+        }
+
+        parser.next_token(); // 前进到模式
+
+        let pattern = parse_pattern(parser)?;
+
+        let guard = if parser.peek_token_is(TokenType::If) {
+            parser.next_token(); // 前进到 if
+            parser.next_token(); // 离开 if
+
+            Some(Box::new(parser.parse_expression(Precedence::Lowest)?))
+        } else {
+            None
+        };
+
+        if !parser.expect_peek(TokenType::FatArrow) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到 =>
+        parser.next_token(); // 离开 =>
+
+        let body = if parser.cur_token_is(TokenType::LBrace) {
+            parse_block_statement(parser)?
+        } else {
+            Statement::ExpressionStatement(ExpressionStatement {
+                expression: Some(Box::new(parser.parse_expression(Precedence::Lowest)?)),
+            })
+        };
+
+        // 分支之间的逗号可省略
+        if parser.peek_token_is(TokenType::Comma) {
+            parser.next_token();
+        }
+
+        arms.push(MatchArm { pattern, guard, body });
+    }
+
+    parser.next_token(); // 前进到右大括号
+
+    parser.mark_block_expression_end();
+
+    Some(Expression::MatchExpression(create_match_expression(
+        token,
+        Box::new(value),
+        arms,
+    )))
+}
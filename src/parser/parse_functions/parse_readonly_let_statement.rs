@@ -0,0 +1,26 @@
+use crate::ast::stmt::Statement;
+use crate::parser::parse_functions::parse_let_statement::parse_let_statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType;
+
+// const x = ... 与 readonly let x = ... 等价, 绑定之后不能被重新赋值
+pub fn parse_readonly_let_statement(parser: &mut Parser) -> Option<Statement> {
+    if parser.cur_token_is(TokenType::Readonly) {
+        if !parser.expect_peek(TokenType::Let) {
+            return None;
+        }
+
+        parser.next_token(); // 前进到 let
+    }
+
+    match parse_let_statement(parser)? {
+        Statement::LetStatement(mut let_stmt) => {
+            let_stmt.readonly = true;
+            Some(Statement::LetStatement(let_stmt))
+        }
+        _ => {
+            parser.push_err(String::from("readonly bindings cannot be destructured"));
+            None
+        }
+    }
+}
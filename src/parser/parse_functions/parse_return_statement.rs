@@ -1,3 +1,5 @@
+use crate::ast::expr::Expression;
+use crate::ast::expressions::tuple_expression::create_tuple_expression;
 use crate::ast::statements::return_statement::create_return_statement;
 use crate::ast::stmt::Statement;
 use crate::parser::parser::Parser;
@@ -9,7 +11,27 @@ pub fn parse_return_statement(parser: &mut Parser) -> Option<Statement> {
 
     parser.next_token(); // 离开 return 词法单元
 
-    let expr = parser.parse_expression(Precedence::Lowest);
+    let mut expr = parser.parse_expression(Precedence::Lowest);
+
+    // return a, b 返回元组
+    if let Some(first) = expr.take_if(|_| parser.peek_token_is(TokenType::Comma)) {
+        let mut expressions = vec![Box::new(first)];
+
+        while parser.peek_token_is(TokenType::Comma) {
+            parser.next_token(); // 前进到逗号
+            parser.next_token(); // 离开逗号
+
+            match parser.parse_expression(Precedence::Lowest) {
+                Some(it) => expressions.push(Box::new(it)),
+                None => return None,
+            }
+        }
+
+        expr = Some(Expression::TupleExpression(create_tuple_expression(
+            token.clone(),
+            expressions,
+        )));
+    }
 
     if parser.peek_token_is(TokenType::Semicolon) {
         parser.next_token();
@@ -0,0 +1,73 @@
+use crate::ast::ast::INode;
+use crate::ast::expressions::identifier::create_identifier;
+use crate::ast::statements::block_statement::BlockStatement;
+use crate::ast::statements::try_statement::create_try_statement;
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType::{Catch, Finally, Ident, LBrace};
+
+use super::parse_block_statement::parse_block_statement;
+
+fn parse_block(parser: &mut Parser, name: &str) -> Option<BlockStatement> {
+    if !parser.expect_peek(LBrace) {
+        return None;
+    }
+
+    parser.next_token(); // 前进到左括号
+
+    let block = parse_block_statement(parser);
+    if block.is_none() {
+        parser.push_err(format!("missing {name} body."));
+        return None;
+    }
+
+    match block.unwrap() {
+        Statement::BlockStatement(it) => Some(it),
+        it => panic!("expected an block, got: {}", it.to_string()),
+    }
+}
+
+pub fn parse_try_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    let block = parse_block(parser, "try")?;
+
+    let mut catch_var = None;
+    let mut catch_block = None;
+    let mut finally_block = None;
+
+    if parser.peek_token_is(Catch) {
+        parser.next_token(); // 前进到 catch 词法单元
+
+        // 可选的错误变量
+        if parser.peek_token_is(Ident) {
+            parser.next_token();
+
+            catch_var = Some(create_identifier(
+                parser.cur_token.clone(),
+                parser.cur_token.value.clone(),
+            ));
+        }
+
+        catch_block = Some(parse_block(parser, "catch")?);
+    }
+
+    if parser.peek_token_is(Finally) {
+        parser.next_token(); // 前进到 finally 词法单元
+
+        finally_block = Some(parse_block(parser, "finally")?);
+    }
+
+    if catch_block.is_none() && finally_block.is_none() {
+        parser.push_err(String::from("expected 'catch' or 'finally' after try block."));
+        return None;
+    }
+
+    Some(Statement::TryStatement(create_try_statement(
+        token,
+        block,
+        catch_var,
+        catch_block,
+        finally_block,
+    )))
+}
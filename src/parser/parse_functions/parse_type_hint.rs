@@ -0,0 +1,29 @@
+use crate::object::object::NULL;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType;
+
+// 解析类型注解 Int 或 Int | String, 开始时当前词法单元为注解前的 ':' 或 '>', 结束时为最后一个类型名
+pub fn parse_type_hint(parser: &mut Parser) -> Option<Vec<String>> {
+    let mut types = vec![];
+
+    loop {
+        parser.next_token(); // 前进到类型名
+
+        match parser.cur_token.token_type {
+            TokenType::Ident => types.push(parser.cur_token.value.clone()),
+            TokenType::None => types.push(NULL.to_string()),
+            _ => {
+                parser.push_err(format!("expected a type name, got: {}", parser.cur_token.value));
+                return None;
+            }
+        }
+
+        if !parser.peek_token_is(TokenType::Pipe) {
+            break;
+        }
+
+        parser.next_token(); // 前进到 |
+    }
+
+    Some(types)
+}
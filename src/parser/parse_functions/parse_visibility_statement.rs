@@ -0,0 +1,26 @@
+use crate::ast::statements::visibility_statement::create_visibility_statement;
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::token::token_type::TokenType;
+
+// public / private 之后为被修饰的成员定义 (let, func 或带装饰器的定义)
+pub fn parse_visibility_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+    let private = token.token_type == TokenType::Private;
+
+    parser.next_token(); // 离开 public / private
+
+    let stmt = match parser.parse_statement() {
+        Some(it) => it,
+        None => {
+            parser.push_err(format!("missing member definition after '{}'", token.value));
+            return None;
+        }
+    };
+
+    Some(Statement::VisibilityStatement(create_visibility_statement(
+        token,
+        private,
+        Box::new(stmt),
+    )))
+}
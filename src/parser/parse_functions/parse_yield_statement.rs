@@ -0,0 +1,25 @@
+use crate::ast::statements::yield_statement::create_yield_statement;
+use crate::ast::stmt::Statement;
+use crate::parser::parser::Parser;
+use crate::parser::precedence::Precedence;
+use crate::token::token_type::TokenType;
+
+pub fn parse_yield_statement(parser: &mut Parser) -> Option<Statement> {
+    let token = parser.cur_token.clone();
+
+    parser.next_token(); // 离开 yield 词法单元
+
+    let value = match parser.parse_expression(Precedence::Lowest) {
+        Some(it) => it,
+        None => {
+            parser.push_err(String::from("missing yield value."));
+            return None;
+        }
+    };
+
+    if parser.peek_token_is(TokenType::Semicolon) {
+        parser.next_token();
+    }
+
+    Some(Statement::YieldStatement(create_yield_statement(token, Box::new(value))))
+}
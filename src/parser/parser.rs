@@ -13,11 +13,15 @@ use crate::parser::parse_functions::parse_call_expression::parse_call_expression
 use crate::parser::parse_functions::parse_class_member_expression::parse_class_member_expression;
 use crate::parser::parse_functions::parse_continue::parse_continue;
 use crate::parser::parse_functions::parse_decorator::parse_decorator;
+use crate::parser::parse_functions::parse_enum_statement::parse_enum_statement;
+use crate::parser::parse_functions::parse_format_string::parse_format_string;
 use crate::parser::parse_functions::parse_hash_literal::parse_hash_literal;
 use crate::parser::parse_functions::parse_index_expression::parse_index_expression;
+use crate::parser::parse_functions::parse_match_expression::parse_match_expression;
 use crate::parser::parse_functions::parse_none::parse_none;
 use crate::parser::parse_functions::parse_prefix_expression::parse_prefix_expression;
 use crate::parser::parse_functions::parse_return_statement::parse_return_statement;
+use crate::parser::parse_functions::parse_yield_statement::parse_yield_statement;
 use crate::parser::parse_functions::parse_test_print_statement::parse_test_print_statement;
 use crate::parser::parse_functions::parse_tuple_expression::parse_tuple_expression;
 use crate::parser::parse_functions::parse_use_statement::parse_use_statement;
@@ -25,7 +29,7 @@ use crate::parser::precedence::*;
 use crate::token::token::Token;
 use crate::ast::expr::Expression;
 use crate::token::token_type::TokenType;
-use crate::token::token_type::TokenType::{Comma, Eof, Nonsense, Semicolon};
+use crate::token::token_type::TokenType::{Comma, Eof, Eol, Nonsense, Semicolon};
 
 use crate::parser::parse_functions::parse_function_expression::parse_function_expression;
 use crate::parser::parse_functions::parse_ident::parse_ident;
@@ -37,8 +41,14 @@ use crate::parser::parse_functions::parse_string::parse_string;
 use crate::parser::precedence::Precedence::Lowest;
 
 use super::parse_functions::parse_class_statement::parse_class_statement;
+use super::parse_functions::parse_for_statement::parse_for_statement;
+use super::parse_functions::parse_from_import_statement::parse_from_import_statement;
+use super::parse_functions::parse_import_statement::parse_import_statement;
+use super::parse_functions::parse_try_statement::parse_try_statement;
 use super::parse_functions::parse_object_member_expression::parse_object_member_expression;
 use super::parse_functions::parse_while_statement::parse_while_statement;
+use super::parse_functions::parse_readonly_let_statement::parse_readonly_let_statement;
+use super::parse_functions::parse_visibility_statement::parse_visibility_statement;
 
 type PrefixParseFn = fn(&mut Parser) -> Option<Expression>;
 type InfixParseFn = fn(&mut Parser, Expression) -> Option<Expression>;
@@ -69,6 +79,12 @@ pub struct Parser {
     pub cur_token: Token,
     pub peek_token: Token,
 
+    // 当前词法单元与下一个词法单元之间是否有换行 (Eol)
+    pub peek_after_newline: bool,
+
+    // 最近一个以代码块结尾的表达式 (if / match / func) 的右大括号所在位置
+    block_expression_end: Option<usize>,
+
     prefix_parse_fn_map: HashMap<TokenType, PrefixParseFn>,
     infix_parse_fn_map: HashMap<TokenType, InfixParseFn>,
     statement_parse_fn_map: HashMap<TokenType, StmtParseFn>,
@@ -95,6 +111,8 @@ impl Parser {
                 0,
                 0,
             ),
+            peek_after_newline: false,
+            block_expression_end: None,
             prefix_parse_fn_map: HashMap::with_capacity(12),
             infix_parse_fn_map: HashMap::with_capacity(12),
             statement_parse_fn_map: HashMap::with_capacity(5),
@@ -103,15 +121,45 @@ impl Parser {
         parser
             .statement_parse_fn_map
             .insert(TokenType::Class, parse_class_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Interface, parse_class_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Enum, parse_enum_statement);
         parser
             .statement_parse_fn_map
             .insert(TokenType::Let, parse_let_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Const, parse_readonly_let_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Readonly, parse_readonly_let_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Public, parse_visibility_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Private, parse_visibility_statement);
         parser
             .statement_parse_fn_map
             .insert(TokenType::While, parse_while_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::For, parse_for_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Try, parse_try_statement);
         parser
             .statement_parse_fn_map
             .insert(TokenType::Use, parse_use_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Import, parse_import_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::From, parse_from_import_statement);
         parser
             .statement_parse_fn_map
             .insert(TokenType::Continue, parse_continue);
@@ -121,6 +169,9 @@ impl Parser {
         parser
             .statement_parse_fn_map
             .insert(TokenType::Return, parse_return_statement);
+        parser
+            .statement_parse_fn_map
+            .insert(TokenType::Yield, parse_yield_statement);
         parser
             .statement_parse_fn_map
             .insert(TokenType::TestPrint, parse_test_print_statement);
@@ -143,9 +194,15 @@ impl Parser {
         parser
             .prefix_parse_fn_map
             .insert(TokenType::String, parse_string);
+        parser
+            .prefix_parse_fn_map
+            .insert(TokenType::FormatString, parse_format_string);
         parser
             .prefix_parse_fn_map
             .insert(TokenType::If, parse_if_expression);
+        parser
+            .prefix_parse_fn_map
+            .insert(TokenType::Match, parse_match_expression);
         parser
             .prefix_parse_fn_map
             .insert(TokenType::Func, parse_function_expression);
@@ -161,6 +218,9 @@ impl Parser {
         parser
             .prefix_parse_fn_map
             .insert(TokenType::Minus, parse_prefix_expression);
+        parser
+            .prefix_parse_fn_map
+            .insert(TokenType::Tilde, parse_prefix_expression);
         parser
             .prefix_parse_fn_map
             .insert(TokenType::None, parse_none);
@@ -184,6 +244,26 @@ impl Parser {
             .infix_parse_fn_map
             .insert(TokenType::Assign, parse_assignment_expression);
 
+        // 复合赋值
+        for token_type in [
+            TokenType::PlusAssign,
+            TokenType::MinusAssign,
+            TokenType::AsteriskAssign,
+            TokenType::SlashAssign,
+            TokenType::PercentAssign,
+            TokenType::FloorDivAssign,
+            TokenType::PowerAssign,
+            TokenType::AmpersandAssign,
+            TokenType::PipeAssign,
+            TokenType::CaretAssign,
+            TokenType::ShiftLeftAssign,
+            TokenType::ShiftRightAssign,
+        ] {
+            parser
+                .infix_parse_fn_map
+                .insert(token_type, parse_assignment_expression);
+        }
+
         parser
             .infix_parse_fn_map
             .insert(TokenType::Dot, parse_object_member_expression);
@@ -202,12 +282,42 @@ impl Parser {
         parser
             .infix_parse_fn_map
             .insert(TokenType::Slash, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::FloorDiv, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::Percent, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::Power, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::Ampersand, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::Pipe, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::Caret, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::ShiftLeft, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::ShiftRight, parse_infix_expression);
         parser
             .infix_parse_fn_map
             .insert(TokenType::Lt, parse_infix_expression);
         parser
             .infix_parse_fn_map
             .insert(TokenType::Gt, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::LtEq, parse_infix_expression);
+        parser
+            .infix_parse_fn_map
+            .insert(TokenType::GtEq, parse_infix_expression);
         parser
             .infix_parse_fn_map
             .insert(TokenType::Eq, parse_infix_expression);
@@ -265,6 +375,33 @@ impl Parser {
         expressions
     }
 
+    // 解析逗号分隔的元素直至 end, 结束时当前词法单元为 end (用于模式与解构目标)
+    pub fn parse_pattern_items<T>(
+        &mut self,
+        end: TokenType,
+        mut parse_item: impl FnMut(&mut Parser) -> Option<T>,
+    ) -> Option<Vec<T>> {
+        let mut items = vec![];
+
+        while !self.peek_token_is(end) {
+            self.next_token();
+
+            items.push(parse_item(self)?);
+
+            if !self.peek_token_is(end) && !self.expect_peek(Comma) {
+                return None;
+            }
+
+            if self.peek_token_is(Comma) {
+                self.next_token();
+            }
+        }
+
+        self.next_token(); // 前进到 end
+
+        Some(items)
+    }
+
     pub fn parse_expression(&mut self, precedence: Precedence) -> Option<Expression> {
         let mut left: Expression;
 
@@ -289,6 +426,7 @@ impl Parser {
         }
 
         while (!self.peek_token_is(Semicolon))
+            && !self.ends_at_newline()
             && precedence < get_token_precedence(self.peek_token.token_type)
         {
             let infix_parse_fn = self.infix_parse_fn_map.get(&self.peek_token.token_type);
@@ -317,6 +455,18 @@ impl Parser {
         Some(left)
     }
 
+    // 以代码块结尾的表达式 (if / match / func) 在换行处结束, 不再与下一行组成中缀表达式
+    // 如 `if n < 2 { return n }` 换行后的 `-1` 是新的语句
+    // 按当前词法单元是否闭合了此类表达式判断, 因此作为右操作数 (`1 + if c { 2 } else { 3 }`) 时同样生效
+    fn ends_at_newline(&self) -> bool {
+        self.peek_after_newline && self.block_expression_end == Some(self.pos)
+    }
+
+    // 由 if / match / func 的解析函数在当前词法单元 (右大括号) 处调用
+    pub fn mark_block_expression_end(&mut self) {
+        self.block_expression_end = Some(self.pos);
+    }
+
     fn parse_expression_statement(&mut self) -> Statement {
         let expression_statement = ExpressionStatement {
             expression: match self.parse_expression(Lowest) {
@@ -347,8 +497,8 @@ impl Parser {
 
     pub fn parse_program(&mut self) -> Program {
         let mut program = Program {
-            token: if !self.tokens.is_empty() {
-                self.tokens[0].clone()
+            token: if let Some(token) = self.tokens.iter().find(|it| it.token_type != Eol) {
+                token.clone()
             } else {
                 Token::new(
                     Nonsense,
@@ -376,13 +526,25 @@ impl Parser {
         program
     }
 
+    // 跳过 Eol 词法单元, 并记录下一个词法单元之前是否有换行
     pub fn next_token(&mut self) {
+        while self.next_pos < self.tokens.len() && self.tokens[self.next_pos].token_type == Eol {
+            self.next_pos += 1;
+        }
+
         if self.next_pos < self.tokens.len() {
             self.pos = self.next_pos;
             self.next_pos += 1;
 
             self.cur_token = self.tokens[self.pos].clone();
 
+            self.peek_after_newline = false;
+
+            while self.next_pos < self.tokens.len() && self.tokens[self.next_pos].token_type == Eol {
+                self.peek_after_newline = true;
+                self.next_pos += 1;
+            }
+
             self.peek_token = if self.next_pos < self.tokens.len() {
                 self.tokens[self.next_pos].clone()
             } else {
@@ -403,6 +565,7 @@ impl Parser {
                 self.peek_token.line,
                 self.peek_token.column,
             );
+            self.peek_after_newline = false;
         }
     }
 
@@ -14,9 +14,14 @@ pub enum Precedence {
     AndOr,       // Or | And
     Equals,      // ==
     LessGreater, // > | <
+    BitOr,       // |
+    BitXor,      // ^
+    BitAnd,      // &
+    Shift,       // << | >>
     Sum,         // +
     Product,     // *
     Prefix,      // -X | !X
+    Power,       // **
     Call,        // myFunction(X)
     Index,       // array[index]
     ObjMember,   // person.Name
@@ -31,13 +36,35 @@ lazy_static! {
         m.insert(TokenType::NotEq, Precedence::Equals);
         m.insert(TokenType::Lt, Precedence::LessGreater);
         m.insert(TokenType::Gt, Precedence::LessGreater);
+        m.insert(TokenType::LtEq, Precedence::LessGreater);
+        m.insert(TokenType::GtEq, Precedence::LessGreater);
+        m.insert(TokenType::Pipe, Precedence::BitOr);
+        m.insert(TokenType::Caret, Precedence::BitXor);
+        m.insert(TokenType::Ampersand, Precedence::BitAnd);
+        m.insert(TokenType::ShiftLeft, Precedence::Shift);
+        m.insert(TokenType::ShiftRight, Precedence::Shift);
         m.insert(TokenType::Plus, Precedence::Sum);
         m.insert(TokenType::Minus, Precedence::Sum);
         m.insert(TokenType::Slash, Precedence::Product);
         m.insert(TokenType::Asterisk, Precedence::Product);
+        m.insert(TokenType::FloorDiv, Precedence::Product);
+        m.insert(TokenType::Percent, Precedence::Product);
+        m.insert(TokenType::Power, Precedence::Power);
         m.insert(TokenType::LParen, Precedence::Call);
         m.insert(TokenType::LBracket, Precedence::Index);
         m.insert(TokenType::Assign, Precedence::Assignment);
+        m.insert(TokenType::PlusAssign, Precedence::Assignment);
+        m.insert(TokenType::MinusAssign, Precedence::Assignment);
+        m.insert(TokenType::AsteriskAssign, Precedence::Assignment);
+        m.insert(TokenType::SlashAssign, Precedence::Assignment);
+        m.insert(TokenType::PercentAssign, Precedence::Assignment);
+        m.insert(TokenType::FloorDivAssign, Precedence::Assignment);
+        m.insert(TokenType::PowerAssign, Precedence::Assignment);
+        m.insert(TokenType::AmpersandAssign, Precedence::Assignment);
+        m.insert(TokenType::PipeAssign, Precedence::Assignment);
+        m.insert(TokenType::CaretAssign, Precedence::Assignment);
+        m.insert(TokenType::ShiftLeftAssign, Precedence::Assignment);
+        m.insert(TokenType::ShiftRightAssign, Precedence::Assignment);
         m.insert(TokenType::Dot, Precedence::ObjMember);
         m.insert(TokenType::GetClassMember, Precedence::ObjMember);
         m.insert(TokenType::BoolOr, Precedence::AndOr);
@@ -72,3 +72,36 @@ fn test_statement_termination() {
         "Parser should not have errors with mixed termination"
     );
 }
+
+#[test]
+fn test_block_expression_newline_termination() {
+    use crate::{ast::ast::INode, lexer::lexer::Lexer, parser::parser::Parser};
+
+    // (代码, 语句数)
+    let tests = vec![
+        // bug_of_ant/parser/if.ant: 换行结束以代码块结尾的表达式
+        ("if n < 2 {\n    return n\n}\n\n-1", 2),
+        ("if n < 2 {\n    return n\n};\n\n-1", 2),
+        ("match n {\n    _ => 1\n}\n-1", 2),
+        ("func (n) { n }\n(1)", 2),
+        // 作为右操作数时同样在换行处结束
+        ("let x = 1 + if c { 2 } else { 3 }\n-1", 2),
+        ("a + if a { 1 } else { 2 }\n-1", 2),
+        ("1 + match n { _ => 1 }\n-1", 2),
+        // 同一行中仍为中缀表达式
+        ("if n < 2 { n } - 1", 1),
+        // 其他表达式跨行时不受影响
+        ("1\n+ 2", 1),
+        ("if n < 2 { n }\nelse { 1 }", 1),
+    ];
+
+    for (code, statements) in tests {
+        let mut lexer = Lexer::new(code.into(), "__test_block_expression_newline_termination__".into());
+        let mut parser = Parser::new(lexer.get_tokens());
+
+        let program = parser.parse_program();
+
+        assert!(!parser.contains_error(), "{code}");
+        assert_eq!(program.statements.len(), statements, "{code}\n{}", program.to_string());
+    }
+}
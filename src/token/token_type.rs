@@ -6,9 +6,11 @@ pub enum TokenType {
     IntegerBig,
     Integer64,
     String,
+    FormatString,
     None,
     Colon,
     Dot,
+    Ellipsis, // ...
     LBracket,
     RBracket,
     LBrace,
@@ -17,7 +19,29 @@ pub enum TokenType {
     Minus,
     Asterisk,
     Slash,
+    FloorDiv, // ~/
+    Percent,
+    Power, // **
+    Ampersand, // &
+    Pipe, // |
+    Caret, // ^
+    Tilde, // ~
+    ShiftLeft, // <<
+    ShiftRight, // >>
+    PlusAssign, // +=
+    MinusAssign, // -=
+    AsteriskAssign, // *=
+    SlashAssign, // /=
+    PercentAssign, // %=
+    FloorDivAssign, // ~/=
+    PowerAssign, // **=
+    AmpersandAssign, // &=
+    PipeAssign, // |=
+    CaretAssign, // ^=
+    ShiftLeftAssign, // <<=
+    ShiftRightAssign, // >>=
     Assign,
+    FatArrow, // =>
     Eq,
     NotEq,
     Bang,
@@ -25,6 +49,8 @@ pub enum TokenType {
     Var,
     Lt,
     Gt,
+    LtEq, // <=
+    GtEq, // >=
     Semicolon,
     Eol,
     Ident,
@@ -68,6 +94,11 @@ pub enum TokenType {
     NumberSign, // #
     Break,
     Continue,
+    Match,
+    Const,
+    Yield,
+    Enum,
+    Interface,
     Eof,
 }
 
@@ -77,6 +108,7 @@ impl TokenType {
         match self {
             TokenType::Colon => ":",
             TokenType::Dot => ".",
+            TokenType::Ellipsis => "...",
             TokenType::LBracket => "[",
             TokenType::RBracket => "]",
             TokenType::LBrace => "{",
@@ -85,7 +117,29 @@ impl TokenType {
             TokenType::Minus => "-",
             TokenType::Asterisk => "*",
             TokenType::Slash => "/",
+            TokenType::FloorDiv => "~/",
+            TokenType::Percent => "%",
+            TokenType::Power => "**",
+            TokenType::Ampersand => "&",
+            TokenType::Pipe => "|",
+            TokenType::Caret => "^",
+            TokenType::Tilde => "~",
+            TokenType::ShiftLeft => "<<",
+            TokenType::ShiftRight => ">>",
+            TokenType::PlusAssign => "+=",
+            TokenType::MinusAssign => "-=",
+            TokenType::AsteriskAssign => "*=",
+            TokenType::SlashAssign => "/=",
+            TokenType::PercentAssign => "%=",
+            TokenType::FloorDivAssign => "~/=",
+            TokenType::PowerAssign => "**=",
+            TokenType::AmpersandAssign => "&=",
+            TokenType::PipeAssign => "|=",
+            TokenType::CaretAssign => "^=",
+            TokenType::ShiftLeftAssign => "<<=",
+            TokenType::ShiftRightAssign => ">>=",
             TokenType::Assign => "=",
+            TokenType::FatArrow => "=>",
             TokenType::Eq => "==",
             TokenType::NotEq => "!=",
             TokenType::Bang => "!",
@@ -93,6 +147,8 @@ impl TokenType {
             TokenType::Lt => "<",
             TokenType::Var => "Var",
             TokenType::Gt => ">",
+            TokenType::LtEq => "<=",
+            TokenType::GtEq => ">=",
             TokenType::Semicolon => ";",
             TokenType::Eol => "New Line",
             TokenType::BoolTrue => "True",
@@ -135,6 +191,7 @@ impl TokenType {
             TokenType::IntegerBig => "Integer",
             TokenType::Integer64 => "Integer64",
             TokenType::String => "String",
+            TokenType::FormatString => "FormatString",
             TokenType::GetClassMember => "::",
             TokenType::TestPrint => "TestPrint",
             TokenType::Comment => "Comment",
@@ -143,6 +200,11 @@ impl TokenType {
             TokenType::NumberSign => "#",
             TokenType::Break => "Break",
             TokenType::Continue => "Continue",
+            TokenType::Match => "Match",
+            TokenType::Const => "Const",
+            TokenType::Yield => "Yield",
+            TokenType::Enum => "Enum",
+            TokenType::Interface => "Interface",
         }
     }
 }
@@ -150,6 +212,7 @@ impl TokenType {
 pub static TOKEN_TYPE_MAP: phf::Map<&'static str, TokenType> = phf_map! {
     ":" => TokenType::Colon,
     "." => TokenType::Dot,
+    "..." => TokenType::Ellipsis,
     "[" => TokenType::LBracket,
     "]" => TokenType::RBracket,
     "{" => TokenType::LBrace,
@@ -158,7 +221,29 @@ pub static TOKEN_TYPE_MAP: phf::Map<&'static str, TokenType> = phf_map! {
     "-" => TokenType::Minus,
     "*" => TokenType::Asterisk,
     "/" => TokenType::Slash,
+    "~/" => TokenType::FloorDiv,
+    "%" => TokenType::Percent,
+    "**" => TokenType::Power,
+    "&" => TokenType::Ampersand,
+    "|" => TokenType::Pipe,
+    "^" => TokenType::Caret,
+    "~" => TokenType::Tilde,
+    "<<" => TokenType::ShiftLeft,
+    ">>" => TokenType::ShiftRight,
+    "+=" => TokenType::PlusAssign,
+    "-=" => TokenType::MinusAssign,
+    "*=" => TokenType::AsteriskAssign,
+    "/=" => TokenType::SlashAssign,
+    "%=" => TokenType::PercentAssign,
+    "~/=" => TokenType::FloorDivAssign,
+    "**=" => TokenType::PowerAssign,
+    "&=" => TokenType::AmpersandAssign,
+    "|=" => TokenType::PipeAssign,
+    "^=" => TokenType::CaretAssign,
+    "<<=" => TokenType::ShiftLeftAssign,
+    ">>=" => TokenType::ShiftRightAssign,
     "=" => TokenType::Assign,
+    "=>" => TokenType::FatArrow,
     "==" => TokenType::Eq,
     "!=" => TokenType::NotEq,
     "<>" => TokenType::NotEq,
@@ -167,6 +252,8 @@ pub static TOKEN_TYPE_MAP: phf::Map<&'static str, TokenType> = phf_map! {
     "VAR" => TokenType::Var,
     "<" => TokenType::Lt,
     ">" => TokenType::Gt,
+    "<=" => TokenType::LtEq,
+    ">=" => TokenType::GtEq,
     ";" => TokenType::Semicolon,
     "\n" => TokenType::Eol,
     "TRUE" => TokenType::BoolTrue,
@@ -209,6 +296,11 @@ pub static TOKEN_TYPE_MAP: phf::Map<&'static str, TokenType> = phf_map! {
     "#" => TokenType::NumberSign,
     "BREAK" => TokenType::Break,
     "CONTINUE" => TokenType::Continue,
+    "MATCH" => TokenType::Match,
+    "CONST" => TokenType::Const,
+    "YIELD" => TokenType::Yield,
+    "ENUM" => TokenType::Enum,
+    "INTERFACE" => TokenType::Interface,
     "\0" => TokenType::Eof,
 };
 